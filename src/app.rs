@@ -1,16 +1,36 @@
-use std::path::Path;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use crate::events::handle_crossterm_events;
-use crate::spec::finder::{SpecSet, find_all_specs};
+use crate::git::{ProgressPoint, read_task_history, read_tasks_at, ref_exists};
+use crate::mermaid::expand_mermaid;
+use crate::spec::cache::ScanCache;
+use crate::spec::compare::diff_tasks;
+use crate::spec::finder::{SpecSet, find_all_specs, find_all_specs_recursive, find_config_errors};
+use crate::spec::hooks::{Hook, find_hooks};
+use crate::spec::outline::{Heading, has_children, parse_outline, visible_headings};
+use crate::spec::parser::ProgressMode;
+use crate::spec::reader::DocumentCache;
+use crate::spec::steering::{SteeringDoc, find_steering_docs};
 use crate::ui::render;
 use color_eyre::Result;
 use ratatui::DefaultTerminal;
+use ratatui::text::Line;
 use ratatui::widgets::ListState;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ViewMode {
     List,
     Detail { spec_index: usize },
+    // ステアリングドキュメントの一覧
+    Steering,
+    // ステアリングドキュメントの表示
+    SteeringDetail { index: usize },
+    // エージェントフックの一覧
+    Hooks,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -18,89 +38,876 @@ pub enum DetailTab {
     Requirements,
     Design,
     Tasks,
+    // 標準の 3 ファイル以外の Markdown（`SpecSet::documents` のインデックス）
+    Document(usize),
+    Traceability,
+    // git の履歴から再構成した tasks.md の進捗の推移
+    History,
+}
+
+impl DetailTab {
+    /// 表示できるタブ（標準の 3 ファイル、追加の Markdown、Traceability、History の順）
+    ///
+    /// # 引数
+    /// * `document_count` - Spec フォルダの追加の Markdown の数
+    pub fn all(document_count: usize) -> Vec<DetailTab> {
+        [DetailTab::Requirements, DetailTab::Design, DetailTab::Tasks]
+            .into_iter()
+            .chain((0..document_count).map(DetailTab::Document))
+            .chain([DetailTab::Traceability, DetailTab::History])
+            .collect()
+    }
+
+    /// タブバーに表示する名前
+    pub fn title(&self, spec: &SpecSet) -> String {
+        match self {
+            DetailTab::Requirements => "Requirements".to_string(),
+            DetailTab::Design => "Design".to_string(),
+            DetailTab::Tasks => "Tasks".to_string(),
+            DetailTab::Document(index) => spec
+                .documents
+                .get(*index)
+                .and_then(|path| path.file_stem())
+                .map_or_else(String::new, |stem| stem.to_string_lossy().to_string()),
+            DetailTab::Traceability => "Traceability".to_string(),
+            DetailTab::History => "History".to_string(),
+        }
+    }
+
+    /// コンテンツ領域の枠に表示するファイル名
+    pub fn file_name(&self, spec: &SpecSet) -> String {
+        match self {
+            DetailTab::Requirements => "requirements.md".to_string(),
+            DetailTab::Design => "design.md".to_string(),
+            DetailTab::Tasks => "tasks.md".to_string(),
+            DetailTab::Document(index) => spec
+                .documents
+                .get(*index)
+                .and_then(|path| path.file_name())
+                .map_or_else(String::new, |name| name.to_string_lossy().to_string()),
+            DetailTab::Traceability => "traceability".to_string(),
+            DetailTab::History => "tasks.md history".to_string(),
+        }
+    }
+}
+
+/// リストビューの並び順
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortKey {
+    Name,
+    Priority,
+    Due,
+    Owner,
+    Status,
+    LastTouched,
+}
+
+impl SortKey {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortKey::Name => "name",
+            SortKey::Priority => "priority",
+            SortKey::Due => "due",
+            SortKey::Owner => "owner",
+            SortKey::Status => "status",
+            SortKey::LastTouched => "last touched",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            SortKey::Name => SortKey::Priority,
+            SortKey::Priority => SortKey::Due,
+            SortKey::Due => SortKey::Owner,
+            SortKey::Owner => SortKey::Status,
+            SortKey::Status => SortKey::LastTouched,
+            SortKey::LastTouched => SortKey::Name,
+        }
+    }
+}
+
+/// キャッシュした行がどのドキュメントのものかを識別するキー
+#[derive(Debug, Clone, PartialEq)]
+struct LinesKey {
+    view_mode: ViewMode,
+    tab: DetailTab,
+    show_mermaid_source: bool,
+}
+
+/// 表示中のドキュメントの見出しと、各見出しの描画後の行位置
+#[derive(Debug, Clone, Default)]
+struct OutlineCache {
+    headings: Rc<Vec<Heading>>,
+    // 見出しと同じ順の、Mermaid 図の展開を反映した表示位置
+    positions: Rc<Vec<usize>>,
 }
 
 #[derive(Debug)]
 pub struct App {
     pub running: bool,
+    // `.kiro/` を含むプロジェクトのルート
+    pub project_root: PathBuf,
+    // 配下のすべての `.kiro/specs` を探索するか（モノレポモード）
+    pub recursive: bool,
+    // 再探索で変更のないファイルの解析を省くためのキャッシュ
+    scan_cache: ScanCache,
     pub spec_sets: Vec<SpecSet>,
+    // `.kiro/kiro-radar.toml` などプロジェクト単位の設定ファイルの誤り（ヘッダーに表示）
+    pub config_errors: Vec<String>,
+    // 読み込めなかった `.kiro/specs` のエラー（ヘッダーに表示）
+    pub scan_errors: Vec<String>,
     pub list_state: ListState,
+    // `.kiro/steering` のステアリングドキュメント
+    pub steering_docs: Vec<SteeringDoc>,
+    // ステアリング一覧の選択状態
+    pub steering_state: ListState,
+    // `.kiro/hooks` のエージェントフック
+    pub hooks: Vec<Hook>,
+    // フック一覧の選択状態
+    pub hooks_state: ListState,
     pub view_mode: ViewMode,
     pub detail_scroll: usize,
     pub active_tab: DetailTab,
+    pub requirement_state: ListState,
+    // アウトラインサイドバーにフォーカスがあるか
+    pub outline_focus: bool,
+    // サイドバーで表示中の見出しの選択状態
+    pub outline_state: ListState,
+    // 折りたたまれている見出しの行番号
+    pub collapsed_sections: HashSet<usize>,
+    // Mermaid 図を描画せずソースのまま表示するか
+    pub show_mermaid_source: bool,
+    // リストビューの並び順
+    pub sort_key: SortKey,
+    // リストビューの絞り込み条件（`owner:alice status:review` など）
+    pub filter: String,
+    // 絞り込み条件を入力中か
+    pub filter_editing: bool,
+    // 進捗の計算方法
+    pub progress_mode: ProgressMode,
+    // `--against` で指定した比較元の git の ref
+    pub against: Option<String>,
+    // 比較元とのタスクの差分を表示しているか
+    pub comparing: bool,
+    // 比較元にあり、作業ツリーで削除された Spec（モノレポモードでは `パッケージ/名前`）
+    pub removed_specs: Vec<String>,
+    // tasks.md のパスごとの git 履歴（History タブを開いたときに読み込み、再読み込みで破棄）
+    pub progress_history: HashMap<PathBuf, Vec<ProgressPoint>>,
+    // 読み込んだドキュメントの内容（再読み込みで破棄）
+    documents: DocumentCache,
+    // 表示中のドキュメントを描画用に変換した行（描画とスクロールで再利用）
+    rendered_lines: RefCell<Option<(LinesKey, Rc<Vec<Line<'static>>>)>>,
+    // 表示中のドキュメントのアウトライン（描画行と同じキーで再利用）
+    outline_cache: RefCell<Option<(LinesKey, OutlineCache)>>,
 }
 
 impl App {
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self::load(path.as_ref(), false)
+    }
+
+    /// 配下のすべての Kiro ワークスペースの Spec を読み込む（モノレポモード）
+    pub fn new_recursive<P: AsRef<Path>>(path: P) -> Self {
+        Self::load(path.as_ref(), true)
+    }
+
+    fn load(path: &Path, recursive: bool) -> Self {
         let mut app = Self {
             running: false,
+            project_root: path.to_path_buf(),
+            recursive,
+            scan_cache: ScanCache::default(),
             spec_sets: Vec::new(),
+            config_errors: Vec::new(),
+            scan_errors: Vec::new(),
             list_state: ListState::default(),
+            steering_docs: Vec::new(),
+            steering_state: ListState::default(),
+            hooks: Vec::new(),
+            hooks_state: ListState::default(),
             view_mode: ViewMode::List,
             detail_scroll: 0,
             active_tab: DetailTab::Requirements,
+            requirement_state: ListState::default(),
+            outline_focus: false,
+            outline_state: ListState::default(),
+            collapsed_sections: HashSet::new(),
+            show_mermaid_source: false,
+            sort_key: SortKey::Name,
+            filter: String::new(),
+            filter_editing: false,
+            progress_mode: ProgressMode::All,
+            against: None,
+            comparing: false,
+            removed_specs: Vec::new(),
+            progress_history: HashMap::new(),
+            documents: DocumentCache::default(),
+            rendered_lines: RefCell::new(None),
+            outline_cache: RefCell::new(None),
         };
 
-        if let Ok(specs) = find_all_specs(path.as_ref()) {
-            app.spec_sets = specs;
+        app.refresh();
+        app
+    }
 
-            if !app.spec_sets.is_empty() {
-                app.list_state.select(Some(0));
+    /// Spec・ステアリング・フックを読み込み直す
+    ///
+    /// 前回から変更のないファイルはキャッシュの解析結果を再利用する。
+    /// 表示用に読み込んだドキュメントはすべて破棄し、次の描画で読み直す。
+    /// 選択中の Spec は並べ替え後も同じ Spec を選択したままにする。
+    pub fn refresh(&mut self) {
+        let selected = self
+            .list_state
+            .selected()
+            .and_then(|index| self.visible_specs().get(index).copied())
+            .map(|index| {
+                let spec = &self.spec_sets[index];
+                (spec.package.clone(), spec.name.clone())
+            });
+
+        let mut scan_errors = Vec::new();
+        let specs = if self.recursive {
+            Ok(find_all_specs_recursive(
+                &self.project_root,
+                &self.scan_cache,
+                &mut scan_errors,
+            ))
+        } else {
+            find_all_specs(&self.project_root, &self.scan_cache)
+        };
+        match specs {
+            Ok(mut specs) => {
+                let key = self.sort_key;
+                specs.sort_by(|a, b| compare_specs(a, b, key));
+                self.spec_sets = specs;
+                // 削除された Spec の解析結果がたまり続けないようにする
+                self.scan_cache.sweep();
             }
+            Err(error) => scan_errors.push(format!(".kiro/specs: {}", error)),
         }
+        self.scan_errors = scan_errors;
+        self.config_errors = self.read_config_errors();
+        if self.comparing {
+            self.apply_comparison();
+        }
+        self.progress_history.clear();
+        self.documents.clear();
+        self.invalidate_lines();
+        self.steering_docs = find_steering_docs(&self.project_root).unwrap_or_default();
+        self.hooks = find_hooks(&self.project_root).unwrap_or_default();
 
-        app
+        let visible = self.visible_specs();
+        let position = selected.and_then(|(package, name)| {
+            visible.iter().position(|&index| {
+                let spec = &self.spec_sets[index];
+                spec.package == package && spec.name == name
+            })
+        });
+        self.list_state.select(match position {
+            Some(position) => Some(position),
+            None => clamp_selection(self.list_state.selected(), visible.len()),
+        });
+        self.steering_state.select(clamp_selection(
+            self.steering_state.selected(),
+            self.steering_docs.len(),
+        ));
+        self.hooks_state.select(clamp_selection(
+            self.hooks_state.selected(),
+            self.hooks.len(),
+        ));
+    }
+
+    /// 各ワークスペースの設定ファイルの誤り（モノレポモードではパッケージのパスを前に付ける）
+    fn read_config_errors(&self) -> Vec<String> {
+        let mut packages: Vec<&str> = if self.recursive {
+            self.spec_sets
+                .iter()
+                .map(|spec| spec.package.as_str())
+                .collect()
+        } else {
+            vec![""]
+        };
+        packages.sort_unstable();
+        packages.dedup();
+
+        packages
+            .into_iter()
+            .flat_map(|package| {
+                find_config_errors(&workspace_root(&self.project_root, package))
+                    .into_iter()
+                    .map(move |error| match package {
+                        "" | "." => error,
+                        package => format!("{package}/{error}"),
+                    })
+            })
+            .collect()
+    }
+
+    /// 比較元の ref（`--against` の指定がなければ `HEAD`）
+    pub fn comparison_ref(&self) -> &str {
+        self.against.as_deref().unwrap_or("HEAD")
+    }
+
+    /// `git_ref` と作業ツリーの比較を有効にする（`--against`）
+    pub fn compare_against(&mut self, git_ref: String) {
+        self.against = Some(git_ref);
+        self.comparing = true;
+        self.apply_comparison();
+        self.invalidate_lines();
+    }
+
+    /// 比較元とのタスクの差分の表示を切り替える
+    pub fn toggle_comparison(&mut self) {
+        self.comparing = !self.comparing;
+        if self.comparing {
+            self.apply_comparison();
+        } else {
+            for spec in &mut self.spec_sets {
+                spec.delta = None;
+            }
+            self.removed_specs.clear();
+        }
+        // Tasks タブの変化の表示を更新する
+        self.invalidate_lines();
+    }
+
+    /// 比較元の ref 時点の tasks.md と比較して各 Spec の差分を設定する
+    ///
+    /// ref が存在しない場合（git リポジトリ外など）は比較を無効にする。
+    fn apply_comparison(&mut self) {
+        let git_ref = self.comparison_ref().to_string();
+        if !ref_exists(&self.project_root, &git_ref) {
+            self.comparing = false;
+            return;
+        }
+
+        // モノレポモードではパッケージ（ワークスペース）ごとに読み出す
+        let specs_dir = |package: &str| {
+            workspace_root(&self.project_root, package)
+                .join(".kiro")
+                .join("specs")
+        };
+        let mut baselines = HashMap::new();
+        if !self.recursive {
+            // すべての Spec が削除されていても比較元を読み出す
+            baselines.insert(String::new(), read_tasks_at(&specs_dir(""), &git_ref));
+        }
+        for spec in &mut self.spec_sets {
+            let baseline = baselines
+                .entry(spec.package.clone())
+                .or_insert_with(|| read_tasks_at(&specs_dir(&spec.package), &git_ref));
+            let base = baseline.get(&spec.name).map_or(&[][..], Vec::as_slice);
+            spec.delta = Some(diff_tasks(base, &spec.task_tree));
+        }
+
+        let mut removed: Vec<String> = baselines
+            .iter()
+            .flat_map(|(package, baseline)| {
+                baseline
+                    .keys()
+                    .filter(|name| {
+                        !self
+                            .spec_sets
+                            .iter()
+                            .any(|spec| spec.package == *package && spec.name == **name)
+                    })
+                    .map(move |name| match package.as_str() {
+                        "" | "." => name.clone(),
+                        package => format!("{package}/{name}"),
+                    })
+            })
+            .collect();
+        removed.sort();
+        self.removed_specs = removed;
+    }
+
+    /// 絞り込み条件に一致する Spec のインデックス（表示順）
+    pub fn visible_specs(&self) -> Vec<usize> {
+        self.spec_sets
+            .iter()
+            .enumerate()
+            .filter(|(_, spec)| spec.matches_filter(&self.filter))
+            .map(|(index, _)| index)
+            .collect()
     }
 
     pub fn next_item(&mut self) {
+        let count = self.visible_specs().len();
+        if count == 0 {
+            return;
+        }
         let i = match self.list_state.selected() {
-            Some(i) => {
-                if i >= self.spec_sets.len() - 1 {
-                    0
-                } else {
-                    i + 1
-                }
-            }
-            None => 0,
+            Some(i) if i + 1 < count => i + 1,
+            _ => 0,
         };
         self.list_state.select(Some(i));
     }
+
     pub fn previous_item(&mut self) {
+        let count = self.visible_specs().len();
+        if count == 0 {
+            return;
+        }
         let i = match self.list_state.selected() {
-            Some(i) => {
-                if i == 0 {
-                    self.spec_sets.len() - 1
-                } else {
-                    i - 1
-                }
-            }
-            None => 0,
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
         };
         self.list_state.select(Some(i));
     }
 
+    /// 並び順を切り替えて Spec を並べ替える
+    pub fn cycle_sort(&mut self) {
+        self.sort_key = self.sort_key.next();
+        let key = self.sort_key;
+        self.spec_sets.sort_by(|a, b| compare_specs(a, b, key));
+        // 並べ替えで Spec のインデックスが変わるため
+        self.invalidate_lines();
+        self.select_first_visible();
+    }
+
+    /// 進捗の計算方法（全タスク / 末端タスク / トップレベル）を切り替える
+    pub fn cycle_progress_mode(&mut self) {
+        self.progress_mode = self.progress_mode.next();
+    }
+
+    /// 絞り込み条件の入力を開始する
+    pub fn start_filter(&mut self) {
+        self.filter_editing = true;
+    }
+
+    pub fn push_filter_char(&mut self, c: char) {
+        self.filter.push(c);
+        self.select_first_visible();
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.select_first_visible();
+    }
+
+    /// 絞り込み条件の入力を確定する
+    pub fn finish_filter(&mut self) {
+        self.filter_editing = false;
+    }
+
+    /// 絞り込み条件を解除する
+    pub fn clear_filter(&mut self) {
+        self.filter.clear();
+        self.filter_editing = false;
+        self.select_first_visible();
+    }
+
+    fn select_first_visible(&mut self) {
+        let has_visible = !self.visible_specs().is_empty();
+        self.list_state.select(has_visible.then_some(0));
+    }
+
     pub fn enter_detail_view(&mut self) {
-        if let Some(selected_index) = self.list_state.selected() {
-            self.view_mode = ViewMode::Detail {
-                spec_index: selected_index,
-            };
+        if let Some(selected_index) = self.list_state.selected()
+            && let Some(&spec_index) = self.visible_specs().get(selected_index)
+        {
+            self.view_mode = ViewMode::Detail { spec_index };
             self.detail_scroll = 0;
             self.active_tab = DetailTab::Requirements;
+            self.reset_outline();
+            self.requirement_state
+                .select(if self.requirement_count() > 0 {
+                    Some(0)
+                } else {
+                    None
+                });
+        }
+    }
+
+    /// 詳細ビューで表示中の Spec
+    pub fn current_spec(&self) -> Option<&SpecSet> {
+        match self.view_mode {
+            ViewMode::Detail { spec_index } => self.spec_sets.get(spec_index),
+            _ => None,
+        }
+    }
+
+    /// Spec 一覧 → ステアリング一覧 → フック一覧の順に切り替える
+    pub fn next_view(&mut self) {
+        self.view_mode = match self.view_mode {
+            ViewMode::List => ViewMode::Steering,
+            ViewMode::Steering => ViewMode::Hooks,
+            _ => ViewMode::List,
+        };
+    }
+
+    /// Spec 一覧に戻る
+    pub fn show_spec_list(&mut self) {
+        self.view_mode = ViewMode::List;
+    }
+
+    pub fn next_steering_doc(&mut self) {
+        let count = self.steering_docs.len();
+        if count == 0 {
+            return;
+        }
+        let i = match self.steering_state.selected() {
+            Some(i) if i + 1 < count => i + 1,
+            _ => 0,
+        };
+        self.steering_state.select(Some(i));
+    }
+
+    pub fn previous_steering_doc(&mut self) {
+        let count = self.steering_docs.len();
+        if count == 0 {
+            return;
+        }
+        let i = match self.steering_state.selected() {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        };
+        self.steering_state.select(Some(i));
+    }
+
+    /// 選択中のステアリングドキュメントを詳細ビューと同じビューアで開く
+    pub fn open_steering_doc(&mut self) {
+        if let Some(index) = self.steering_state.selected()
+            && index < self.steering_docs.len()
+        {
+            self.view_mode = ViewMode::SteeringDetail { index };
+            self.detail_scroll = 0;
+            self.reset_outline();
+        }
+    }
+
+    pub fn next_hook(&mut self) {
+        let count = self.hooks.len();
+        if count == 0 {
+            return;
         }
+        let i = match self.hooks_state.selected() {
+            Some(i) if i + 1 < count => i + 1,
+            _ => 0,
+        };
+        self.hooks_state.select(Some(i));
+    }
+
+    pub fn previous_hook(&mut self) {
+        let count = self.hooks.len();
+        if count == 0 {
+            return;
+        }
+        let i = match self.hooks_state.selected() {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        };
+        self.hooks_state.select(Some(i));
+    }
+
+    /// フック一覧で選択中のフック
+    pub fn selected_hook(&self) -> Option<&Hook> {
+        self.hooks_state
+            .selected()
+            .and_then(|index| self.hooks.get(index))
+    }
+
+    /// 表示中のステアリングドキュメント
+    pub fn current_steering_doc(&self) -> Option<&SteeringDoc> {
+        match self.view_mode {
+            ViewMode::SteeringDetail { index } => self.steering_docs.get(index),
+            _ => None,
+        }
+    }
+
+    /// 表示中の Spec の解析済み要件数
+    fn requirement_count(&self) -> usize {
+        self.current_spec()
+            .map_or(0, |spec| spec.requirement_list.len())
+    }
+
+    /// Requirements タブで要件リストを操作できるか
+    pub fn navigates_requirements(&self) -> bool {
+        self.active_tab == DetailTab::Requirements && self.requirement_count() > 0
+    }
+
+    pub fn next_requirement(&mut self) {
+        let count = self.requirement_count();
+        if count == 0 {
+            return;
+        }
+        let i = match self.requirement_state.selected() {
+            Some(i) if i + 1 < count => i + 1,
+            _ => 0,
+        };
+        self.requirement_state.select(Some(i));
+    }
+
+    pub fn previous_requirement(&mut self) {
+        let count = self.requirement_count();
+        if count == 0 {
+            return;
+        }
+        let i = match self.requirement_state.selected() {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        };
+        self.requirement_state.select(Some(i));
     }
 
     pub fn next_tab(&mut self) {
-        self.active_tab = match self.active_tab {
-            DetailTab::Requirements => DetailTab::Design,
-            DetailTab::Design => DetailTab::Tasks,
-            DetailTab::Tasks => DetailTab::Requirements,
+        let document_count = self.current_spec().map_or(0, |spec| spec.documents.len());
+        let tabs = DetailTab::all(document_count);
+        let position = tabs.iter().position(|tab| *tab == self.active_tab);
+        self.active_tab = match position {
+            Some(position) => tabs[(position + 1) % tabs.len()].clone(),
+            None => DetailTab::Requirements,
         };
         self.detail_scroll = 0;
+        self.reset_outline();
+        if self.active_tab == DetailTab::History {
+            self.load_progress_history();
+        }
+    }
+
+    /// 表示中の Spec の tasks.md の git 履歴を読み込む（読み込み済みなら何もしない）
+    ///
+    /// コミットごとに tasks.md を解析するため、History タブを開いたときにだけ読み込む。
+    fn load_progress_history(&mut self) {
+        let Some(tasks) = self.current_spec().and_then(|spec| spec.tasks.clone()) else {
+            return;
+        };
+        self.progress_history
+            .entry(tasks)
+            .or_insert_with_key(|tasks| read_task_history(tasks));
+    }
+
+    /// 表示中の Spec の tasks.md の git 履歴（古い順）
+    pub fn current_progress_history(&self) -> &[ProgressPoint] {
+        self.current_spec()
+            .and_then(|spec| spec.tasks.as_ref())
+            .and_then(|tasks| self.progress_history.get(tasks))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// 表示中のタブ（またはステアリングドキュメント）の生テキスト（Traceability タブは `None`）
+    ///
+    /// 表示中のファイルだけを読み込み、内容は再読み込みまでキャッシュする。
+    pub fn document_text(&self) -> Option<Rc<str>> {
+        if let Some(doc) = self.current_steering_doc() {
+            return self.documents.read(&Some(doc.path.clone()));
+        }
+        let spec = self.current_spec()?;
+        match self.active_tab {
+            DetailTab::Requirements => self.documents.read(&spec.requirements),
+            DetailTab::Design => self.documents.read(&spec.design),
+            DetailTab::Tasks => self.documents.read(&spec.tasks),
+            DetailTab::Document(index) => self.documents.read(&spec.documents.get(index).cloned()),
+            DetailTab::Traceability | DetailTab::History => None,
+        }
+    }
+
+    /// 表示中のドキュメントの描画用の行
+    ///
+    /// 表示中のビュー・タブ・Mermaid の表示方法が前回と同じであれば、`build` を呼ばずに
+    /// 前回の行を返す。描画とスクロール位置の計算で同じ行を共有する。
+    ///
+    /// # 引数
+    /// * `build` - キャッシュがない場合に行を構築する処理
+    ///
+    /// # 戻り値
+    /// * `Rc<Vec<Line<'static>>>` - 表示中のドキュメントの行
+    pub fn rendered_lines(
+        &self,
+        build: impl FnOnce(&App) -> Vec<Line<'static>>,
+    ) -> Rc<Vec<Line<'static>>> {
+        let key = self.lines_key();
+        if let Some((cached, lines)) = self.rendered_lines.borrow().as_ref()
+            && *cached == key
+        {
+            return Rc::clone(lines);
+        }
+
+        let lines = Rc::new(build(self));
+        *self.rendered_lines.borrow_mut() = Some((key, Rc::clone(&lines)));
+        lines
+    }
+
+    /// 表示中のドキュメントを識別するキー
+    fn lines_key(&self) -> LinesKey {
+        LinesKey {
+            view_mode: self.view_mode.clone(),
+            tab: self.active_tab.clone(),
+            show_mermaid_source: self.show_mermaid_source,
+        }
+    }
+
+    /// キャッシュした描画用の行とアウトラインを破棄する（表示内容が変わる操作の後に呼ぶ）
+    fn invalidate_lines(&mut self) {
+        *self.rendered_lines.get_mut() = None;
+        *self.outline_cache.get_mut() = None;
+    }
+
+    /// 表示中のドキュメントにアウトラインサイドバーを表示するか
+    ///
+    /// 生テキストとして表示されるタブ（Design、追加の Markdown と、要件を解析できなかった
+    /// Requirements）とステアリングドキュメントが対象。
+    pub fn outline_available(&self) -> bool {
+        if self.current_steering_doc().is_some() {
+            return true;
+        }
+        match self.active_tab {
+            DetailTab::Design | DetailTab::Document(_) => true,
+            DetailTab::Requirements => !self.navigates_requirements(),
+            DetailTab::Tasks | DetailTab::Traceability | DetailTab::History => false,
+        }
+    }
+
+    /// 表示中のドキュメントの見出し一覧
+    pub fn outline(&self) -> Rc<Vec<Heading>> {
+        self.cached_outline().headings
+    }
+
+    /// 表示中のドキュメントの見出しと表示位置（`LinesKey` が同じ間は解析し直さない）
+    fn cached_outline(&self) -> OutlineCache {
+        let key = self.lines_key();
+        if let Some((cached, outline)) = self.outline_cache.borrow().as_ref()
+            && *cached == key
+        {
+            return outline.clone();
+        }
+
+        let outline = self.build_outline();
+        *self.outline_cache.borrow_mut() = Some((key, outline.clone()));
+        outline
+    }
+
+    /// ドキュメントを解析して見出しと表示位置を求める
+    ///
+    /// Mermaid 図を描画している場合は、図の行数の差を反映した表示位置にする。
+    fn build_outline(&self) -> OutlineCache {
+        if !self.outline_available() {
+            return OutlineCache::default();
+        }
+        let Some(text) = self.document_text() else {
+            return OutlineCache::default();
+        };
+
+        let headings = parse_outline(&text);
+        let positions = if self.show_mermaid_source {
+            headings.iter().map(|heading| heading.line - 1).collect()
+        } else {
+            let document_lines = expand_mermaid(&text);
+            headings
+                .iter()
+                .map(|heading| {
+                    document_lines
+                        .iter()
+                        .position(|document_line| document_line.source_line >= heading.line)
+                        .unwrap_or(heading.line - 1)
+                })
+                .collect()
+        };
+        OutlineCache {
+            headings: Rc::new(headings),
+            positions: Rc::new(positions),
+        }
+    }
+
+    /// 折りたたみを反映した、サイドバーに表示する見出しのインデックス
+    pub fn visible_outline(&self, headings: &[Heading]) -> Vec<usize> {
+        visible_headings(headings, &self.collapsed_sections)
+    }
+
+    fn reset_outline(&mut self) {
+        self.outline_focus = false;
+        self.collapsed_sections.clear();
+        self.outline_state.select(None);
+    }
+
+    /// アウトラインサイドバーへのフォーカスを切り替える
+    pub fn toggle_outline_focus(&mut self) {
+        if self.outline_focus {
+            self.outline_focus = false;
+            return;
+        }
+        if self.outline().is_empty() {
+            return;
+        }
+        self.outline_focus = true;
+        if self.outline_state.selected().is_none() {
+            self.outline_state.select(Some(0));
+        }
+    }
+
+    pub fn next_heading(&mut self) {
+        let count = self.visible_outline(&self.outline()).len();
+        if count == 0 {
+            return;
+        }
+        let i = match self.outline_state.selected() {
+            Some(i) if i + 1 < count => i + 1,
+            _ => 0,
+        };
+        self.outline_state.select(Some(i));
+    }
+
+    pub fn previous_heading(&mut self) {
+        let count = self.visible_outline(&self.outline()).len();
+        if count == 0 {
+            return;
+        }
+        let i = match self.outline_state.selected() {
+            Some(0) | None => count - 1,
+            Some(i) => i - 1,
+        };
+        self.outline_state.select(Some(i));
+    }
+
+    /// 選択中の見出し（サイドバー上のインデックスを見出し一覧のインデックスに変換）
+    fn selected_heading(&self, headings: &[Heading]) -> Option<usize> {
+        let visible = self.visible_outline(headings);
+        self.outline_state
+            .selected()
+            .and_then(|i| visible.get(i).copied())
+    }
+
+    /// 選択中の見出しの折りたたみを切り替える
+    pub fn toggle_section(&mut self) {
+        let headings = self.outline();
+        let Some(index) = self.selected_heading(&headings) else {
+            return;
+        };
+        if !has_children(&headings, index) {
+            return;
+        }
+
+        let line = headings[index].line;
+        if !self.collapsed_sections.remove(&line) {
+            self.collapsed_sections.insert(line);
+        }
+    }
+
+    /// 選択中の見出しの位置までスクロールする
+    ///
+    /// Mermaid 図を描画している場合は、図の行数の差を反映した表示位置に移動する。
+    pub fn jump_to_heading(&mut self, max_scroll: usize) {
+        let outline = self.cached_outline();
+        let Some(index) = self.selected_heading(&outline.headings) else {
+            return;
+        };
+        self.detail_scroll = outline.positions[index].min(max_scroll);
+    }
+
+    /// Mermaid 図の描画とソース表示を切り替える
+    pub fn toggle_mermaid_source(&mut self) {
+        self.show_mermaid_source = !self.show_mermaid_source;
+        self.detail_scroll = 0;
     }
 
     pub fn exit_detail_view(&mut self) {
-        self.view_mode = ViewMode::List;
+        self.view_mode = match self.view_mode {
+            ViewMode::SteeringDetail { .. } => ViewMode::Steering,
+            _ => ViewMode::List,
+        };
         self.detail_scroll = 0;
+        // 折りたたみは行番号で記録しているため、別のドキュメントに持ち越さない
+        self.reset_outline();
     }
 
     pub fn scroll_down(&mut self, max_scroll: usize) {
@@ -123,9 +930,61 @@ impl App {
     }
 }
 
+/// パッケージ（モノレポモードでの探索ルートからの相対パス）のワークスペースのルート
+fn workspace_root(project_root: &Path, package: &str) -> PathBuf {
+    match package {
+        "" | "." => project_root.to_path_buf(),
+        package => project_root.join(package),
+    }
+}
+
+/// 項目数が変わった後の選択位置（範囲外なら末尾、項目がなければ選択なし）
+fn clamp_selection(selected: Option<usize>, count: usize) -> Option<usize> {
+    match count {
+        0 => None,
+        _ => Some(selected.unwrap_or(0).min(count - 1)),
+    }
+}
+
+/// 並び順に従って 2 つの Spec を比較する
+///
+/// モノレポモードではパッケージごとにまとめたうえで並べる。値のない Spec は最後、
+/// 同順位は名前順。
+fn compare_specs(a: &SpecSet, b: &SpecSet, key: SortKey) -> Ordering {
+    let by_field = |field: &str| match (a.metadata.field(field), b.metadata.field(field)) {
+        (Some(x), Some(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+    let ordering = match key {
+        SortKey::Name => Ordering::Equal,
+        SortKey::Priority => a.metadata.priority_rank().cmp(&b.metadata.priority_rank()),
+        SortKey::Due => by_field("due"),
+        SortKey::Owner => by_field("owner"),
+        SortKey::Status => by_field("status"),
+        // 最近変更された順（git の履歴がない Spec は最後）
+        SortKey::LastTouched => match (&a.git, &b.git) {
+            (Some(x), Some(y)) => y.timestamp.cmp(&x.timestamp),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        },
+    };
+    a.package
+        .cmp(&b.package)
+        .then(ordering)
+        .then_with(|| a.name.cmp(&b.name))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::git::GitInfo;
+    use crate::spec::metadata::SpecMetadata;
+    use crate::spec::parser::count_tasks;
+    use crate::spec::requirements::parse_requirements;
+    use std::fs;
     use tempfile::TempDir;
 
     #[test]
@@ -144,11 +1003,7 @@ mod tests {
         // Spec を追加して選択状態にする
         app.spec_sets.push(SpecSet {
             name: "test-spec".to_string(),
-            requirements: None,
-            design: None,
-            tasks: None,
-            total_tasks: None,
-            completed_tasks: None,
+            ..Default::default()
         });
         app.list_state.select(Some(0));
 
@@ -166,11 +1021,7 @@ mod tests {
         // Spec を追加
         app.spec_sets.push(SpecSet {
             name: "test-spec".to_string(),
-            requirements: None,
-            design: None,
-            tasks: None,
-            total_tasks: None,
-            completed_tasks: None,
+            ..Default::default()
         });
         app.list_state.select(Some(0));
 
@@ -190,11 +1041,7 @@ mod tests {
         // Spec を追加して詳細ビューに遷移
         app.spec_sets.push(SpecSet {
             name: "test-spec".to_string(),
-            requirements: None,
-            design: None,
-            tasks: None,
-            total_tasks: None,
-            completed_tasks: None,
+            ..Default::default()
         });
         app.list_state.select(Some(0));
         app.enter_detail_view();
@@ -298,11 +1145,309 @@ mod tests {
         app.next_tab();
         assert_eq!(app.active_tab, DetailTab::Tasks);
 
-        // Tasks → Requirements（循環）
+        // Tasks → Traceability
+        app.next_tab();
+        assert_eq!(app.active_tab, DetailTab::Traceability);
+
+        // Traceability → History
+        app.next_tab();
+        assert_eq!(app.active_tab, DetailTab::History);
+
+        // History → Requirements（循環）
         app.next_tab();
         assert_eq!(app.active_tab, DetailTab::Requirements);
     }
 
+    #[test]
+    fn test_next_tab_includes_extra_documents() {
+        let temp_dir = TempDir::new().unwrap();
+        let research = temp_dir.path().join("research.md");
+        fs::write(&research, "# Research\n## Findings\n").unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            documents: vec![research],
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        // 標準の 3 タブの後に追加の Markdown、その後に Traceability
+        for _ in 0..3 {
+            app.next_tab();
+        }
+        assert_eq!(app.active_tab, DetailTab::Document(0));
+        assert_eq!(app.active_tab.title(&app.spec_sets[0]), "research");
+        assert_eq!(
+            app.document_text().as_deref(),
+            Some("# Research\n## Findings\n")
+        );
+        assert_eq!(app.outline().len(), 2);
+
+        app.next_tab();
+        assert_eq!(app.active_tab, DetailTab::Traceability);
+    }
+
+    #[test]
+    fn test_document_text_reads_active_tab_until_refresh() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join(".kiro").join("specs").join("alpha");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(spec_dir.join("requirements.md"), "# Requirements\n").unwrap();
+        fs::write(spec_dir.join("design.md"), "# Design\n").unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        assert_eq!(app.document_text().as_deref(), Some("# Requirements\n"));
+
+        // 表示していないタブのファイルは開くまで読み込まない
+        fs::write(spec_dir.join("design.md"), "# Design v2\n").unwrap();
+        app.next_tab();
+        assert_eq!(app.document_text().as_deref(), Some("# Design v2\n"));
+
+        // 一度読んだ内容は再読み込みまで使い回す
+        fs::write(spec_dir.join("design.md"), "# Design v3\n").unwrap();
+        assert_eq!(app.document_text().as_deref(), Some("# Design v2\n"));
+        app.refresh();
+        assert_eq!(app.document_text().as_deref(), Some("# Design v3\n"));
+    }
+
+    #[test]
+    fn test_rendered_lines_reused_until_view_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        let builds = std::cell::Cell::new(0);
+        let build = |_: &App| {
+            builds.set(builds.get() + 1);
+            vec![Line::from("line")]
+        };
+
+        // 描画とスクロールで同じ行を共有する
+        app.rendered_lines(build);
+        app.scroll_down(10);
+        app.rendered_lines(build);
+        assert_eq!(builds.get(), 1);
+
+        // タブ・Mermaid の表示方法・比較の切り替えで作り直す
+        app.next_tab();
+        app.rendered_lines(build);
+        app.toggle_mermaid_source();
+        app.rendered_lines(build);
+        app.toggle_comparison();
+        app.rendered_lines(build);
+        assert_eq!(builds.get(), 4);
+    }
+
+    #[test]
+    fn test_history_tab_loads_on_demand() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks = temp_dir.path().join("tasks.md");
+        fs::write(&tasks, "- [ ] 1. Task\n").unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            tasks: Some(tasks.clone()),
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        // History タブを開くまでは履歴を読み込まない
+        while app.active_tab != DetailTab::History {
+            assert!(app.progress_history.is_empty());
+            app.next_tab();
+        }
+
+        // git リポジトリ外なので履歴は空、再読み込みで破棄される
+        assert!(app.progress_history.contains_key(&tasks));
+        assert!(app.current_progress_history().is_empty());
+        app.refresh();
+        assert!(app.progress_history.is_empty());
+    }
+
+    #[test]
+    fn test_history_tab_reads_every_commit() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(["-c", "user.name=dev", "-c", "user.email=dev@example.com"])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        let tasks = root
+            .join(".kiro")
+            .join("specs")
+            .join("api")
+            .join("tasks.md");
+        fs::create_dir_all(tasks.parent().unwrap()).unwrap();
+        git(&["init", "-q"]);
+        for content in [
+            "- [ ] 1. Design\n",
+            "- [x] 1. Design\n- [ ] 2. Build\n",
+            "- [x] 1. Design\n- [x] 2. Build\n- [ ] 3. Ship\n",
+            "- [x] 1. Design\n- [x] 2. Build\n- [x] 3. Ship\n",
+        ] {
+            fs::write(&tasks, content).unwrap();
+            git(&["add", "."]);
+            git(&["commit", "-q", "-m", "Update tasks"]);
+        }
+
+        let mut app = App::new(root);
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+        while app.active_tab != DetailTab::History {
+            app.next_tab();
+        }
+
+        // コミットごとの tasks.md を古い順に解析する
+        let counts: Vec<(usize, usize)> = app
+            .current_progress_history()
+            .iter()
+            .map(|point| count_tasks(&point.tasks, ProgressMode::All))
+            .collect();
+        assert_eq!(counts, vec![(1, 0), (2, 1), (3, 2), (3, 3)]);
+    }
+
+    #[test]
+    fn test_compare_against_git_ref() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .arg("-C")
+                .arg(root)
+                .args(["-c", "user.name=dev", "-c", "user.email=dev@example.com"])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success());
+        };
+        let tasks = root
+            .join(".kiro")
+            .join("specs")
+            .join("api")
+            .join("tasks.md");
+        fs::create_dir_all(tasks.parent().unwrap()).unwrap();
+        fs::write(&tasks, "- [ ] 1. Build\n- [ ] 2. Old\n").unwrap();
+        let legacy = root.join(".kiro").join("specs").join("legacy");
+        fs::create_dir_all(&legacy).unwrap();
+        fs::write(legacy.join("tasks.md"), "- [x] 1. Done\n").unwrap();
+        git(&["init", "-q"]);
+        git(&["add", "."]);
+        git(&["commit", "-q", "-m", "Add api"]);
+        fs::write(&tasks, "- [x] 1. Build\n- [ ] 2. Test\n").unwrap();
+        // 作業ツリーで Spec ごと削除する
+        fs::remove_dir_all(&legacy).unwrap();
+
+        let mut app = App::new(root);
+        app.compare_against("HEAD".to_string());
+
+        let delta = app.spec_sets[0].delta.as_ref().unwrap();
+        assert_eq!(delta.summary(), "+1 done, 1 new task, 1 removed");
+        assert_eq!(app.removed_specs, vec!["legacy".to_string()]);
+
+        // 比較を切り替えると差分を消す
+        app.toggle_comparison();
+        assert!(!app.comparing);
+        assert!(app.spec_sets[0].delta.is_none());
+        assert!(app.removed_specs.is_empty());
+    }
+
+    #[test]
+    fn test_config_errors_reported_per_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        let web = temp_dir.path().join("apps").join("web").join(".kiro");
+        fs::create_dir_all(web.join("specs").join("ui")).unwrap();
+        fs::write(web.join("kiro-radar.toml"), "[estimates\n").unwrap();
+        let api = temp_dir.path().join("apps").join("api").join(".kiro");
+        fs::create_dir_all(api.join("specs").join("rest")).unwrap();
+
+        let mut app = App::new_recursive(temp_dir.path());
+
+        assert_eq!(app.config_errors.len(), 1);
+        assert!(
+            app.config_errors[0].starts_with("apps/web/.kiro/kiro-radar.toml: "),
+            "{}",
+            app.config_errors[0]
+        );
+        assert!(app.spec_sets.iter().all(|spec| spec.errors.is_empty()));
+
+        // 直せば再読み込みで消える
+        fs::remove_file(web.join("kiro-radar.toml")).unwrap();
+        app.refresh();
+        assert!(app.config_errors.is_empty());
+    }
+
+    #[test]
+    fn test_toggle_comparison_outside_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+
+        // 比較元の ref がなければ比較を有効にしない
+        app.toggle_comparison();
+        assert!(!app.comparing);
+    }
+
+    #[test]
+    fn test_open_steering_doc() {
+        let temp_dir = TempDir::new().unwrap();
+        let steering_dir = temp_dir.path().join(".kiro").join("steering");
+        fs::create_dir_all(&steering_dir).unwrap();
+        fs::write(steering_dir.join("product.md"), "# Product\n## Users\n").unwrap();
+        fs::write(steering_dir.join("tech.md"), "# Tech\n").unwrap();
+        let mut app = App::new(temp_dir.path());
+
+        app.next_view();
+        assert_eq!(app.view_mode, ViewMode::Steering);
+        app.next_steering_doc();
+        app.open_steering_doc();
+
+        // 詳細ビューと同じくテキストとアウトラインを提供する
+        assert_eq!(app.view_mode, ViewMode::SteeringDetail { index: 1 });
+        assert_eq!(app.document_text().as_deref(), Some("# Tech\n"));
+        assert_eq!(app.outline().len(), 1);
+
+        // 戻るとステアリング一覧、Spec 一覧にも戻れる
+        app.exit_detail_view();
+        assert_eq!(app.view_mode, ViewMode::Steering);
+        app.show_spec_list();
+        assert_eq!(app.view_mode, ViewMode::List);
+    }
+
+    #[test]
+    fn test_next_view_cycles_through_hooks() {
+        let temp_dir = TempDir::new().unwrap();
+        let hooks_dir = temp_dir.path().join(".kiro").join("hooks");
+        fs::create_dir_all(&hooks_dir).unwrap();
+        fs::write(hooks_dir.join("a.kiro.hook"), "{}").unwrap();
+        fs::write(hooks_dir.join("b.kiro.hook"), "{}").unwrap();
+        let mut app = App::new(temp_dir.path());
+
+        app.next_view();
+        app.next_view();
+        assert_eq!(app.view_mode, ViewMode::Hooks);
+        app.next_hook();
+        assert_eq!(app.selected_hook().unwrap().file_name, "b.kiro.hook");
+        app.next_hook();
+        assert_eq!(app.selected_hook().unwrap().file_name, "a.kiro.hook");
+
+        app.next_view();
+        assert_eq!(app.view_mode, ViewMode::List);
+    }
+
     #[test]
     fn test_next_tab_resets_scroll() {
         let temp_dir = TempDir::new().unwrap();
@@ -318,6 +1463,155 @@ mod tests {
         assert_eq!(app.detail_scroll, 0);
     }
 
+    #[test]
+    fn test_requirement_navigation_wraps() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            requirement_list: parse_requirements(
+                "### Requirement 1\n### Requirement 2\n### Requirement 3",
+            ),
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+
+        // 詳細ビューに遷移すると先頭の要件が選択される
+        app.enter_detail_view();
+        assert!(app.navigates_requirements());
+        assert_eq!(app.requirement_state.selected(), Some(0));
+
+        app.previous_requirement();
+        assert_eq!(app.requirement_state.selected(), Some(2));
+
+        app.next_requirement();
+        assert_eq!(app.requirement_state.selected(), Some(0));
+
+        // 他のタブでは要件リストを操作しない
+        app.next_tab();
+        assert!(!app.navigates_requirements());
+    }
+
+    #[test]
+    fn test_outline_navigation_and_jump() {
+        let temp_dir = TempDir::new().unwrap();
+        let design = temp_dir.path().join("design.md");
+        fs::write(
+            &design,
+            "# Design\n\n## Overview\n\ntext\n\n## Architecture\n\n### Modules\n\nmore",
+        )
+        .unwrap();
+
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            design: Some(design),
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        // Requirements タブにはファイルがないため見出しもない
+        app.toggle_outline_focus();
+        assert!(!app.outline_focus);
+
+        // Design タブでアウトラインにフォーカス
+        app.next_tab();
+        app.toggle_outline_focus();
+        assert!(app.outline_focus);
+        assert_eq!(app.outline_state.selected(), Some(0));
+
+        // Architecture（7 行目）へジャンプ
+        app.next_heading();
+        app.next_heading();
+        app.jump_to_heading(100);
+        assert_eq!(app.detail_scroll, 6);
+
+        // Architecture を折りたたむと Modules が隠れる
+        app.toggle_section();
+        let headings = app.outline();
+        assert_eq!(app.visible_outline(&headings), vec![0, 1, 2]);
+
+        // 末尾から先頭へ循環
+        app.next_heading();
+        assert_eq!(app.outline_state.selected(), Some(0));
+
+        // タブ切り替えでアウトラインの状態はリセットされる
+        app.next_tab();
+        assert!(!app.outline_focus);
+        assert!(app.collapsed_sections.is_empty());
+    }
+
+    #[test]
+    fn test_outline_state_not_carried_to_another_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        for name in ["alpha", "beta"] {
+            let requirements = temp_dir.path().join(format!("{name}.md"));
+            fs::write(&requirements, "# Requirements\n\n## Scope\n\n### Users\n").unwrap();
+            app.spec_sets.push(SpecSet {
+                name: name.to_string(),
+                requirements: Some(requirements),
+                ..Default::default()
+            });
+        }
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+
+        // 1 つ目の Spec で Scope を折りたたむ
+        app.toggle_outline_focus();
+        app.next_heading();
+        app.toggle_section();
+        assert!(!app.collapsed_sections.is_empty());
+
+        // 別の Spec を開くと何も折りたたまれていない
+        app.exit_detail_view();
+        assert!(!app.outline_focus);
+        app.next_item();
+        app.enter_detail_view();
+        assert_eq!(app.current_spec().unwrap().name, "beta");
+        assert!(!app.outline_focus);
+        assert!(app.collapsed_sections.is_empty());
+        assert_eq!(app.outline_state.selected(), None);
+    }
+
+    #[test]
+    fn test_outline_reused_until_view_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let design = temp_dir.path().join("design.md");
+        fs::write(
+            &design,
+            "# Design\n\n```mermaid\nflowchart TD\n    A --> B\n```\n\n## Data\n",
+        )
+        .unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets.push(SpecSet {
+            name: "test-spec".to_string(),
+            design: Some(design),
+            ..Default::default()
+        });
+        app.list_state.select(Some(0));
+        app.enter_detail_view();
+        app.next_tab();
+
+        // 描画やキー入力のたびに解析し直さない
+        let outline = app.outline();
+        assert_eq!(outline.len(), 2);
+        assert!(Rc::ptr_eq(&outline, &app.outline()));
+
+        // Mermaid のソース表示では見出しの表示位置が変わるため作り直す
+        app.toggle_outline_focus();
+        app.next_heading();
+        app.jump_to_heading(100);
+        let rendered = app.detail_scroll;
+        app.toggle_mermaid_source();
+        assert!(!Rc::ptr_eq(&outline, &app.outline()));
+        app.jump_to_heading(100);
+        assert_eq!(app.detail_scroll, 7);
+        assert_ne!(rendered, 7);
+    }
+
     #[test]
     fn test_enter_detail_view_sets_requirements_tab() {
         let temp_dir = TempDir::new().unwrap();
@@ -326,11 +1620,7 @@ mod tests {
         // Spec を追加
         app.spec_sets.push(SpecSet {
             name: "test-spec".to_string(),
-            requirements: None,
-            design: None,
-            tasks: None,
-            total_tasks: None,
-            completed_tasks: None,
+            ..Default::default()
         });
         app.list_state.select(Some(0));
 
@@ -343,4 +1633,142 @@ mod tests {
         // active_tab が Requirements にリセットされる
         assert_eq!(app.active_tab, DetailTab::Requirements);
     }
+
+    fn spec_with_metadata(name: &str, owner: Option<&str>, priority: Option<&str>) -> SpecSet {
+        SpecSet {
+            name: name.to_string(),
+            metadata: SpecMetadata {
+                owner: owner.map(str::to_string),
+                priority: priority.map(str::to_string),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_cycle_sort_by_priority() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets = vec![
+            spec_with_metadata("alpha", None, Some("low")),
+            spec_with_metadata("beta", None, None),
+            spec_with_metadata("gamma", None, Some("high")),
+        ];
+
+        // Name -> Priority
+        app.cycle_sort();
+
+        assert_eq!(app.sort_key, SortKey::Priority);
+        let names: Vec<&str> = app.spec_sets.iter().map(|s| s.name.as_str()).collect();
+        // 優先度のない Spec は最後
+        assert_eq!(names, vec!["gamma", "alpha", "beta"]);
+        assert_eq!(app.list_state.selected(), Some(0));
+    }
+
+    #[test]
+    fn test_cycle_sort_by_last_touched() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        let touched = |name: &str, timestamp: Option<i64>| SpecSet {
+            name: name.to_string(),
+            git: timestamp.map(|timestamp| GitInfo {
+                last_commit: "abc1234".to_string(),
+                author: "alice".to_string(),
+                timestamp,
+                date: "2026-01-01".to_string(),
+                commit_count: 1,
+            }),
+            ..Default::default()
+        };
+        app.spec_sets = vec![
+            touched("alpha", Some(100)),
+            touched("beta", None),
+            touched("gamma", Some(300)),
+        ];
+
+        while app.sort_key != SortKey::LastTouched {
+            app.cycle_sort();
+        }
+
+        // 最近変更された順、履歴のない Spec は最後
+        let names: Vec<&str> = app.spec_sets.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["gamma", "alpha", "beta"]);
+    }
+
+    #[test]
+    fn test_cycle_sort_keeps_packages_grouped() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        let in_package = |package: &str, name: &str, priority: &str| SpecSet {
+            package: package.to_string(),
+            ..spec_with_metadata(name, None, Some(priority))
+        };
+        app.spec_sets = vec![
+            in_package("apps/web", "login", "low"),
+            in_package("apps/web", "signup", "high"),
+            in_package("services/billing", "invoices", "critical"),
+        ];
+
+        app.cycle_sort();
+
+        // パッケージの中で優先度順に並ぶ
+        let names: Vec<&str> = app.spec_sets.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["signup", "login", "invoices"]);
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let specs_dir = temp_dir.path().join(".kiro").join("specs");
+        for name in ["beta", "delta"] {
+            fs::create_dir_all(specs_dir.join(name)).unwrap();
+        }
+        let mut app = App::new(temp_dir.path());
+        app.next_item();
+        assert_eq!(app.spec_sets[1].name, "delta");
+
+        // 前に Spec が追加されても同じ Spec を選択したまま
+        fs::create_dir_all(specs_dir.join("alpha")).unwrap();
+        fs::write(specs_dir.join("delta").join("tasks.md"), "- [x] 1. Done\n").unwrap();
+        app.refresh();
+
+        assert_eq!(app.spec_sets.len(), 3);
+        assert_eq!(app.list_state.selected(), Some(2));
+        assert_eq!(app.spec_sets[2].completed_tasks(ProgressMode::All), 1);
+
+        // 選択中の Spec が消えた場合は範囲内に収める
+        fs::remove_dir_all(specs_dir.join("delta")).unwrap();
+        app.refresh();
+        assert_eq!(app.list_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_filter_selects_matching_specs() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut app = App::new(temp_dir.path());
+        app.spec_sets = vec![
+            spec_with_metadata("auth", Some("alice"), None),
+            spec_with_metadata("billing", Some("bob"), None),
+            spec_with_metadata("search", Some("Alice"), None),
+        ];
+
+        app.start_filter();
+        for c in "owner:alice".chars() {
+            app.push_filter_char(c);
+        }
+        app.finish_filter();
+
+        assert!(!app.filter_editing);
+        assert_eq!(app.visible_specs(), vec![0, 2]);
+
+        // 選択は絞り込み後の一覧の位置を指す
+        app.next_item();
+        app.enter_detail_view();
+        assert_eq!(app.view_mode, ViewMode::Detail { spec_index: 2 });
+
+        app.exit_detail_view();
+        app.clear_filter();
+        assert_eq!(app.visible_specs().len(), 3);
+    }
 }
@@ -17,12 +17,21 @@ fn on_key_event(app: &mut App, key: KeyEvent) {
     // ViewMode に応じて処理を分岐
     match &app.view_mode {
         ViewMode::List => handle_list_view_keys(app, key),
-        ViewMode::Detail { .. } => handle_detail_view_keys(app, key),
+        ViewMode::Detail { .. } | ViewMode::SteeringDetail { .. } => {
+            handle_detail_view_keys(app, key)
+        }
+        ViewMode::Steering => handle_steering_view_keys(app, key),
+        ViewMode::Hooks => handle_hooks_view_keys(app, key),
     }
 }
 
 /// リストビューでのキーイベント処理
 fn handle_list_view_keys(app: &mut App, key: KeyEvent) {
+    if app.filter_editing {
+        handle_filter_input_keys(app, key);
+        return;
+    }
+
     match (key.modifiers, key.code) {
         // 終了操作
         (_, KeyCode::Char('q'))
@@ -32,6 +41,62 @@ fn handle_list_view_keys(app: &mut App, key: KeyEvent) {
         (_, KeyCode::Up | KeyCode::Char('k')) => app.previous_item(),
         // 詳細ビューへの遷移
         (_, KeyCode::Enter) => app.enter_detail_view(),
+        // 並べ替えと絞り込み
+        (_, KeyCode::Char('s')) => app.cycle_sort(),
+        (_, KeyCode::Char('/')) => app.start_filter(),
+        // 進捗の計算方法
+        (_, KeyCode::Char('p')) => app.cycle_progress_mode(),
+        // 再読み込み
+        (_, KeyCode::Char('r')) => app.refresh(),
+        // git の ref との比較
+        (_, KeyCode::Char('c')) => app.toggle_comparison(),
+        // ステアリング一覧への切り替え
+        (_, KeyCode::Tab) => app.next_view(),
+        (_, KeyCode::Esc) => app.clear_filter(),
+        _ => {}
+    }
+}
+
+/// ステアリング一覧でのキーイベント処理
+fn handle_steering_view_keys(app: &mut App, key: KeyEvent) {
+    match (key.modifiers, key.code) {
+        // 終了操作
+        (_, KeyCode::Char('q'))
+        | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => quit(app),
+        (_, KeyCode::Down | KeyCode::Char('j')) => app.next_steering_doc(),
+        (_, KeyCode::Up | KeyCode::Char('k')) => app.previous_steering_doc(),
+        (_, KeyCode::Enter) => app.open_steering_doc(),
+        (_, KeyCode::Char('r')) => app.refresh(),
+        // フック一覧 / Spec 一覧への切り替え
+        (_, KeyCode::Tab) => app.next_view(),
+        (_, KeyCode::Esc) => app.show_spec_list(),
+        _ => {}
+    }
+}
+
+/// フック一覧でのキーイベント処理
+fn handle_hooks_view_keys(app: &mut App, key: KeyEvent) {
+    match (key.modifiers, key.code) {
+        // 終了操作
+        (_, KeyCode::Char('q'))
+        | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => quit(app),
+        (_, KeyCode::Down | KeyCode::Char('j')) => app.next_hook(),
+        (_, KeyCode::Up | KeyCode::Char('k')) => app.previous_hook(),
+        (_, KeyCode::Char('r')) => app.refresh(),
+        // Spec 一覧への切り替え
+        (_, KeyCode::Tab | KeyCode::Esc) => app.show_spec_list(),
+        _ => {}
+    }
+}
+
+/// 絞り込み条件の入力中のキーイベント処理
+fn handle_filter_input_keys(app: &mut App, key: KeyEvent) {
+    match (key.modifiers, key.code) {
+        (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => quit(app),
+        (_, KeyCode::Enter) => app.finish_filter(),
+        (_, KeyCode::Esc) => app.clear_filter(),
+        (_, KeyCode::Backspace) => app.pop_filter_char(),
+        (_, KeyCode::Char(c)) => app.push_filter_char(c),
         _ => {}
     }
 }
@@ -46,10 +111,31 @@ fn handle_detail_view_keys(app: &mut App, key: KeyEvent) {
         // 終了操作
         (_, KeyCode::Char('q'))
         | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => quit(app),
-        // リストビューへの復帰
+        // アウトラインのフォーカス解除 / リストビューへの復帰
+        (_, KeyCode::Esc) if app.outline_focus => app.toggle_outline_focus(),
         (_, KeyCode::Esc) => app.exit_detail_view(),
+        // アウトラインサイドバー
+        (_, KeyCode::Char('o')) => app.toggle_outline_focus(),
+        // Mermaid 図の描画 / ソース表示の切り替え
+        (_, KeyCode::Char('m')) => app.toggle_mermaid_source(),
+        (_, KeyCode::Down | KeyCode::Char('j')) if app.outline_focus => app.next_heading(),
+        (_, KeyCode::Up | KeyCode::Char('k')) if app.outline_focus => app.previous_heading(),
+        (_, KeyCode::Char(' ') | KeyCode::Left | KeyCode::Right) if app.outline_focus => {
+            app.toggle_section()
+        }
+        (_, KeyCode::Enter) if app.outline_focus => {
+            let max_scroll = calculate_max_scroll(app, ESTIMATED_CONTENT_HEIGHT);
+            app.jump_to_heading(max_scroll);
+        }
         // タブ切り替え
-        (_, KeyCode::Tab) => app.next_tab(),
+        (_, KeyCode::Tab) if app.current_spec().is_some() => app.next_tab(),
+        // 要件リストの選択
+        (_, KeyCode::Down | KeyCode::Char('j')) if app.navigates_requirements() => {
+            app.next_requirement()
+        }
+        (_, KeyCode::Up | KeyCode::Char('k')) if app.navigates_requirements() => {
+            app.previous_requirement()
+        }
         // スクロール操作
         (_, KeyCode::Down | KeyCode::Char('j')) => {
             let max_scroll = calculate_max_scroll(app, ESTIMATED_CONTENT_HEIGHT);
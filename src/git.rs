@@ -0,0 +1,523 @@
+use std::{
+    collections::HashMap,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::spec::parser::{Task, parse_tasks};
+
+/// コミットの区切り（`git log --format` の先頭に付ける）
+const RECORD_SEPARATOR: char = '\x1e';
+/// コミット情報のフィールドの区切り
+const FIELD_SEPARATOR: char = '\x1f';
+
+/// Spec フォルダの git 履歴の要約
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitInfo {
+    // フォルダを最後に変更したコミット（短縮ハッシュ）
+    pub last_commit: String,
+    // 最後のコミットの作者
+    pub author: String,
+    // 最後のコミットの日時（UNIX 時間）
+    pub timestamp: i64,
+    // 最後のコミットの日付（`YYYY-MM-DD`）
+    pub date: String,
+    // フォルダを変更したコミットの数
+    pub commit_count: usize,
+}
+
+impl GitInfo {
+    /// 最後のコミットからの経過時間（`3d ago` など）
+    pub fn age(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs() as i64);
+        format_age(now - self.timestamp)
+    }
+}
+
+/// tasks.md のあるコミット時点のタスクツリー
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressPoint {
+    // コミットの短縮ハッシュ
+    pub commit: String,
+    // コミット日時（UNIX 時間）
+    pub timestamp: i64,
+    // コミット日（`YYYY-MM-DD`）
+    pub date: String,
+    // その時点の tasks.md を解析したタスクツリー
+    pub tasks: Vec<Task>,
+}
+
+/// `dir` で git コマンドを実行して標準出力を返す
+///
+/// ローカルの `.git` だけを読むため、オフラインでも動作する。
+///
+/// # 引数
+/// * `dir` - git を実行するディレクトリ
+/// * `args` - git のサブコマンドと引数
+///
+/// # 戻り値
+/// * `Some(String)` - 成功した場合の標準出力
+/// * `None` - git がない、リポジトリ外、コマンドが失敗した場合
+fn run_git(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["-c", "core.quotePath=false"])
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8(output.stdout).ok()
+}
+
+/// `git cat-file --batch` で複数のファイルの内容を 1 回のプロセス起動でまとめて読み出す
+///
+/// # 引数
+/// * `dir` - git を実行するディレクトリ
+/// * `objects` - `<commit>:<path>` 形式のオブジェクト名
+///
+/// # 戻り値
+/// * `Vec<Option<String>>` - `objects` と同じ順の内容（存在しない、または UTF-8 でなければ `None`）
+fn read_blobs(dir: &Path, objects: &[String]) -> Vec<Option<String>> {
+    let child = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["cat-file", "--batch"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        return Vec::new();
+    };
+
+    // 出力が詰まって止まらないよう、オブジェクト名は別のスレッドで書き込む
+    let input: String = objects.iter().map(|object| format!("{object}\n")).collect();
+    let stdin = child.stdin.take();
+    let writer = thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(input.as_bytes());
+        }
+    });
+    let output = child.wait_with_output();
+    let _ = writer.join();
+
+    match output {
+        Ok(output) if output.status.success() => parse_batch_output(&output.stdout),
+        _ => Vec::new(),
+    }
+}
+
+/// `git cat-file --batch` の出力をオブジェクトごとの内容に分ける
+///
+/// 見つかったオブジェクトは `<oid> <type> <size>` の行に続いて内容が、
+/// 見つからないオブジェクトは `<name> missing` の行だけが出力される。
+fn parse_batch_output(mut output: &[u8]) -> Vec<Option<String>> {
+    let mut blobs = Vec::new();
+    while let Some(end) = output.iter().position(|&byte| byte == b'\n') {
+        let header = String::from_utf8_lossy(&output[..end]);
+        output = &output[end + 1..];
+        let size = header
+            .rsplit(' ')
+            .next()
+            .and_then(|size| size.parse::<usize>().ok());
+        match size {
+            Some(size) if size <= output.len() => {
+                blobs.push(String::from_utf8(output[..size].to_vec()).ok());
+                output = &output[size..];
+                output = output.strip_prefix(b"\n").unwrap_or(output);
+            }
+            Some(_) => break,
+            None => blobs.push(None),
+        }
+    }
+    blobs
+}
+
+/// `git log --name-only` の出力を Spec フォルダごとに集計する
+///
+/// ファイルのパスは `.kiro/specs` からの相対パスで、先頭のディレクトリ名を Spec 名とする。
+/// コミットは新しい順に並んでいるものとして、最初に現れたコミットを最後の変更とする。
+///
+/// # 引数
+/// * `output` - `git log` の出力
+///
+/// # 戻り値
+/// * `HashMap<String, GitInfo>` - Spec 名ごとの履歴の要約
+fn parse_spec_log(output: &str) -> HashMap<String, GitInfo> {
+    let mut history: HashMap<String, GitInfo> = HashMap::new();
+
+    for record in output.split(RECORD_SEPARATOR) {
+        let mut lines = record.lines();
+        let Some(header) = lines.next() else {
+            continue;
+        };
+        let fields: Vec<&str> = header.split(FIELD_SEPARATOR).collect();
+        let [hash, author, timestamp, date] = fields[..] else {
+            continue;
+        };
+
+        // 1 つのコミットで同じ Spec の複数のファイルを変更しても 1 回と数える
+        let mut specs: Vec<&str> = lines
+            .filter_map(|path| path.split_once('/').map(|(spec, _)| spec))
+            .collect();
+        specs.sort_unstable();
+        specs.dedup();
+
+        for spec in specs {
+            history
+                .entry(spec.to_string())
+                .and_modify(|info| info.commit_count += 1)
+                .or_insert_with(|| GitInfo {
+                    last_commit: hash.to_string(),
+                    author: author.to_string(),
+                    timestamp: timestamp.parse().unwrap_or_default(),
+                    date: date.to_string(),
+                    commit_count: 1,
+                });
+        }
+    }
+
+    history
+}
+
+/// `.kiro/specs` 配下の各 Spec フォルダの git 履歴を読み取る
+///
+/// # 引数
+/// * `specs_dir` - `.kiro/specs` ディレクトリ
+///
+/// # 戻り値
+/// * `HashMap<String, GitInfo>` - Spec 名ごとの履歴の要約（git が使えなければ空）
+pub fn read_spec_history(specs_dir: &Path) -> HashMap<String, GitInfo> {
+    let format = format!(
+        "--format={RECORD_SEPARATOR}%h{FIELD_SEPARATOR}%an{FIELD_SEPARATOR}%at{FIELD_SEPARATOR}%as"
+    );
+    run_git(
+        specs_dir,
+        &["log", &format, "--name-only", "--relative", "--", "."],
+    )
+    .map(|output| parse_spec_log(&output))
+    .unwrap_or_default()
+}
+
+/// tasks.md の git 履歴をたどり、各コミット時点のタスクツリーを解析する
+///
+/// ファイル名の変更（Spec フォルダの改名）も追跡する。
+/// 各コミット時点の内容は `git cat-file --batch` でまとめて読み出し、読み出せなかったコミットは飛ばす。
+///
+/// # 引数
+/// * `tasks_path` - tasks.md のパス
+///
+/// # 戻り値
+/// * `Vec<ProgressPoint>` - 古い順の各コミット時点のタスクツリー（git が使えなければ空）
+pub fn read_task_history(tasks_path: &Path) -> Vec<ProgressPoint> {
+    let (Some(dir), Some(file_name)) = (tasks_path.parent(), tasks_path.file_name()) else {
+        return Vec::new();
+    };
+    let format = format!("--format={RECORD_SEPARATOR}%h{FIELD_SEPARATOR}%at{FIELD_SEPARATOR}%as");
+    let file_name = file_name.to_string_lossy();
+    let Some(output) = run_git(
+        dir,
+        &["log", &format, "--name-only", "--follow", "--", &file_name],
+    ) else {
+        return Vec::new();
+    };
+
+    let commits: Vec<(&str, &str, &str, &str)> = output
+        .split(RECORD_SEPARATOR)
+        .filter_map(|record| {
+            let mut lines = record.lines().filter(|line| !line.is_empty());
+            let header = lines.next()?;
+            // `--name-only` のパスはリポジトリのルートからの相対パス
+            let path = lines.next()?;
+            let [commit, timestamp, date] = header.split(FIELD_SEPARATOR).collect::<Vec<_>>()[..]
+            else {
+                return None;
+            };
+            Some((commit, timestamp, date, path))
+        })
+        .collect();
+    let objects: Vec<String> = commits
+        .iter()
+        .map(|(commit, _, _, path)| format!("{commit}:{path}"))
+        .collect();
+    let contents = read_blobs(dir, &objects);
+
+    let mut points: Vec<ProgressPoint> = commits
+        .iter()
+        .zip(contents)
+        .filter_map(|((commit, timestamp, date, _), content)| {
+            Some(ProgressPoint {
+                commit: commit.to_string(),
+                timestamp: timestamp.parse().unwrap_or_default(),
+                date: date.to_string(),
+                tasks: parse_tasks(&content?),
+            })
+        })
+        .collect();
+    points.reverse();
+    points
+}
+
+/// HEAD のコミットのハッシュ
+///
+/// # 引数
+/// * `dir` - リポジトリ内のディレクトリ
+///
+/// # 戻り値
+/// * `Some(String)` - HEAD のハッシュ
+/// * `None` - git リポジトリ外、またはコミットがない場合
+pub fn head_commit(dir: &Path) -> Option<String> {
+    run_git(dir, &["rev-parse", "--verify", "--quiet", "HEAD"])
+        .map(|output| output.trim().to_string())
+}
+
+/// git の ref（ブランチ名・タグ・コミット）が存在するか
+///
+/// # 引数
+/// * `dir` - リポジトリ内のディレクトリ
+/// * `git_ref` - 確認する ref
+pub fn ref_exists(dir: &Path, git_ref: &str) -> bool {
+    run_git(
+        dir,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("{git_ref}^{{commit}}"),
+        ],
+    )
+    .is_some()
+}
+
+/// git の ref 時点の各 Spec の tasks.md を、チェックアウトせずに読み出して解析する
+///
+/// # 引数
+/// * `specs_dir` - `.kiro/specs` ディレクトリ
+/// * `git_ref` - 比較元の ref
+///
+/// # 戻り値
+/// * `HashMap<String, Vec<Task>>` - Spec 名ごとのタスクツリー（ref 時点で tasks.md のある Spec のみ）
+pub fn read_tasks_at(specs_dir: &Path, git_ref: &str) -> HashMap<String, Vec<Task>> {
+    // パスを指定しない `ls-tree` は作業ディレクトリからの相対パスで一覧を返す
+    let Some(output) = run_git(specs_dir, &["ls-tree", "-r", "--name-only", git_ref]) else {
+        return HashMap::new();
+    };
+
+    let specs: Vec<&str> = output
+        .lines()
+        .filter_map(|path| path.strip_suffix("/tasks.md"))
+        .filter(|spec| !spec.contains('/'))
+        .collect();
+    let objects: Vec<String> = specs
+        .iter()
+        .map(|spec| format!("{git_ref}:./{spec}/tasks.md"))
+        .collect();
+
+    specs
+        .iter()
+        .zip(read_blobs(specs_dir, &objects))
+        .filter_map(|(spec, content)| Some((spec.to_string(), parse_tasks(&content?))))
+        .collect()
+}
+
+/// 経過時間を `3d ago` のような短い表記にする
+///
+/// # 引数
+/// * `seconds` - 経過秒数
+fn format_age(seconds: i64) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+
+    if seconds < MINUTE {
+        "just now".to_string()
+    } else if seconds < HOUR {
+        format!("{}m ago", seconds / MINUTE)
+    } else if seconds < DAY {
+        format!("{}h ago", seconds / HOUR)
+    } else if seconds < 30 * DAY {
+        format!("{}d ago", seconds / DAY)
+    } else if seconds < 365 * DAY {
+        format!("{}mo ago", seconds / (30 * DAY))
+    } else {
+        format!("{}y ago", seconds / (365 * DAY))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::spec::parser::{ProgressMode, count_tasks};
+
+    /// テスト用の git リポジトリでコミットする
+    fn commit(dir: &Path, author: &str, message: &str) {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(["-c", &format!("user.name={author}")])
+            .args(["-c", "user.email=dev@example.com"])
+            .args(["-c", "commit.gpgsign=false"])
+            .args(["commit", "-q", "-m", message])
+            .status()
+            .unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn test_parse_spec_log() {
+        let output = "\x1eb2\x1fBob\x1f200\x1f2026-02-01\n\napi/tasks.md\napi/design.md\n\
+                      \x1ea1\x1fAlice\x1f100\x1f2026-01-01\n\napi/requirements.md\nauth/tasks.md\n";
+
+        let history = parse_spec_log(output);
+
+        let api = &history["api"];
+        assert_eq!(api.last_commit, "b2");
+        assert_eq!(api.author, "Bob");
+        assert_eq!(api.timestamp, 200);
+        assert_eq!(api.date, "2026-02-01");
+        assert_eq!(api.commit_count, 2);
+        assert_eq!(history["auth"].author, "Alice");
+        assert_eq!(history["auth"].commit_count, 1);
+    }
+
+    #[test]
+    fn test_read_spec_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        run_git(root, &["init", "-q"]).unwrap();
+        let specs_dir = root.join(".kiro").join("specs");
+        fs::create_dir_all(specs_dir.join("api")).unwrap();
+        fs::create_dir_all(specs_dir.join("auth")).unwrap();
+
+        fs::write(specs_dir.join("api").join("tasks.md"), "- [ ] 1. Task").unwrap();
+        fs::write(specs_dir.join("auth").join("tasks.md"), "- [ ] 1. Task").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Alice", "Add specs");
+        fs::write(specs_dir.join("api").join("tasks.md"), "- [x] 1. Task").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Bob", "Complete api task");
+
+        let history = read_spec_history(&specs_dir);
+
+        assert_eq!(
+            head_commit(&specs_dir).map(|head| head.len()),
+            Some(40),
+            "HEAD は完全なハッシュで返す"
+        );
+        assert_eq!(history["api"].author, "Bob");
+        assert_eq!(history["api"].commit_count, 2);
+        assert_eq!(history["auth"].author, "Alice");
+        assert_eq!(history["auth"].commit_count, 1);
+    }
+
+    #[test]
+    fn test_parse_batch_output() {
+        let output = b"a1 blob 5\n- [x]\nb2:tasks.md missing\nc3 blob 0\n\n";
+
+        let blobs = parse_batch_output(output);
+
+        assert_eq!(
+            blobs,
+            vec![Some("- [x]".to_string()), None, Some(String::new())]
+        );
+    }
+
+    #[test]
+    fn test_read_task_history() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        run_git(root, &["init", "-q"]).unwrap();
+        let spec_dir = root.join(".kiro").join("specs").join("api");
+        fs::create_dir_all(&spec_dir).unwrap();
+        let tasks = spec_dir.join("tasks.md");
+
+        fs::write(&tasks, "- [ ] 1. Design\n- [ ] 2. Build\n").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Alice", "Plan");
+        fs::write(&tasks, "- [x] 1. Design\n- [ ] 2. Build\n- [ ] 3. Ship\n").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Alice", "Design done");
+
+        // Spec フォルダを改名しても履歴をたどる
+        let renamed = root.join(".kiro").join("specs").join("public-api");
+        run_git(root, &["mv", ".kiro/specs/api", ".kiro/specs/public-api"]).unwrap();
+        commit(root, "Bob", "Rename spec");
+        let tasks = renamed.join("tasks.md");
+        fs::write(&tasks, "- [x] 1. Design\n- [x] 2. Build\n- [ ] 3. Ship\n").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Bob", "Build done");
+
+        let history = read_task_history(&tasks);
+
+        let counts: Vec<(usize, usize)> = history
+            .iter()
+            .map(|point| count_tasks(&point.tasks, ProgressMode::All))
+            .collect();
+        assert_eq!(counts, vec![(2, 0), (3, 1), (3, 1), (3, 2)]);
+        assert!(
+            history
+                .windows(2)
+                .all(|pair| pair[0].timestamp <= pair[1].timestamp)
+        );
+    }
+
+    #[test]
+    fn test_read_tasks_at() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        run_git(root, &["init", "-q"]).unwrap();
+        let specs_dir = root.join(".kiro").join("specs");
+        fs::create_dir_all(specs_dir.join("api")).unwrap();
+        fs::write(specs_dir.join("api").join("tasks.md"), "- [ ] 1. Build\n").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Alice", "Add api");
+        run_git(root, &["tag", "v1"]).unwrap();
+
+        // ref より後の変更は読まない
+        fs::write(specs_dir.join("api").join("tasks.md"), "- [x] 1. Build\n").unwrap();
+        fs::create_dir_all(specs_dir.join("auth")).unwrap();
+        fs::write(specs_dir.join("auth").join("tasks.md"), "- [ ] 1. Login\n").unwrap();
+        run_git(root, &["add", "."]).unwrap();
+        commit(root, "Alice", "Progress");
+
+        assert!(ref_exists(&specs_dir, "v1"));
+        assert!(!ref_exists(&specs_dir, "no-such-branch"));
+
+        let tasks = read_tasks_at(&specs_dir, "v1");
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks["api"][0].title, "Build");
+        assert!(!tasks["api"][0].is_completed());
+        assert_eq!(read_tasks_at(&specs_dir, "HEAD").len(), 2);
+    }
+
+    #[test]
+    fn test_read_spec_history_outside_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(head_commit(temp_dir.path()), None);
+        assert!(read_spec_history(temp_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(30), "just now");
+        assert_eq!(format_age(5 * 60), "5m ago");
+        assert_eq!(format_age(3 * 3600), "3h ago");
+        assert_eq!(format_age(4 * 86400), "4d ago");
+        assert_eq!(format_age(90 * 86400), "3mo ago");
+        assert_eq!(format_age(800 * 86400), "2y ago");
+    }
+}
@@ -1,23 +1,49 @@
 mod app;
 mod events;
+mod git;
+mod mermaid;
 mod spec;
 mod ui;
 
 use app::App;
 use clap::Parser;
+use color_eyre::eyre::eyre;
+use git::ref_exists;
+use spec::finder::find_project_root;
 
 #[derive(Parser)]
 #[command(name = "kiro-radar")]
 #[command(version)]
 #[command(about, long_about = None)]
-struct Cli {}
+struct Cli {
+    /// Discover every .kiro/specs under the current directory (monorepo mode)
+    #[arg(short, long)]
+    recursive: bool,
+
+    /// Compare task progress against a git ref (branch, tag or commit)
+    #[arg(long, value_name = "REF")]
+    against: Option<String>,
+}
 
 fn main() -> color_eyre::Result<()> {
-    let _cli = Cli::parse();
+    let cli = Cli::parse();
 
     color_eyre::install()?;
+    let current_dir = std::env::current_dir()?;
+    let mut app = if cli.recursive {
+        App::new_recursive(current_dir)
+    } else {
+        let project_root = find_project_root(&current_dir).unwrap_or(current_dir);
+        App::new(project_root)
+    };
+    if let Some(git_ref) = cli.against {
+        if !ref_exists(&app.project_root, &git_ref) {
+            return Err(eyre!("unknown git ref: {}", git_ref));
+        }
+        app.compare_against(git_ref);
+    }
     let terminal = ratatui::init();
-    let result = App::new(".").run(terminal);
+    let result = app.run(terminal);
     ratatui::restore();
     result
 }
@@ -0,0 +1,315 @@
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const UP: u8 = 1;
+const DOWN: u8 = 2;
+const LEFT: u8 = 4;
+const RIGHT: u8 = 8;
+
+/// 図全体の最大表示幅（ボックスを並べる際の折り返し位置）
+pub const MAX_WIDTH: usize = 100;
+/// ボックス内の 1 行の最大表示幅
+const MAX_BOX_TEXT_WIDTH: usize = 40;
+
+/// 罫線と文字を配置するための固定幅キャンバス
+///
+/// 罫線は各セルの上下左右の接続として記録し、出力時に交点を含めた
+/// 罫線文字（`┼` `├` など）に変換する。
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    // 文字セル（全角文字の右半分は '\0'）
+    text: Vec<Vec<Option<char>>>,
+    // 罫線の接続方向
+    lines: Vec<Vec<u8>>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            text: vec![vec![None; width]; height],
+            lines: vec![vec![0; width]; height],
+        }
+    }
+
+    /// 文字列を表示幅に従って配置する（はみ出した部分は切り捨てる）
+    pub fn put_str(&mut self, x: usize, y: usize, s: &str) {
+        if y >= self.height {
+            return;
+        }
+        let mut column = x;
+        for c in s.chars() {
+            let width = c.width().unwrap_or(0);
+            if width == 0 {
+                continue;
+            }
+            if column + width > self.width {
+                break;
+            }
+            self.text[y][column] = Some(c);
+            if width == 2 {
+                self.text[y][column + 1] = Some('\0');
+            }
+            column += width;
+        }
+    }
+
+    /// 1 文字を配置する（矢印など）
+    pub fn put_char(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width && y < self.height {
+            self.text[y][x] = Some(c);
+        }
+    }
+
+    /// 水平線を引く
+    pub fn hline(&mut self, x1: usize, x2: usize, y: usize) {
+        let (start, end) = (x1.min(x2), x1.max(x2));
+        for x in start..=end.min(self.width.saturating_sub(1)) {
+            if x > start {
+                self.connect(x, y, LEFT);
+            }
+            if x < end {
+                self.connect(x, y, RIGHT);
+            }
+        }
+    }
+
+    /// 垂直線を引く
+    pub fn vline(&mut self, x: usize, y1: usize, y2: usize) {
+        let (start, end) = (y1.min(y2), y1.max(y2));
+        for y in start..=end.min(self.height.saturating_sub(1)) {
+            if y > start {
+                self.connect(x, y, UP);
+            }
+            if y < end {
+                self.connect(x, y, DOWN);
+            }
+        }
+    }
+
+    /// 矩形の枠線を引く
+    pub fn rect(&mut self, x: usize, y: usize, width: usize, height: usize) {
+        let right = x + width - 1;
+        let bottom = y + height - 1;
+        self.hline(x, right, y);
+        self.hline(x, right, bottom);
+        self.vline(x, y, bottom);
+        self.vline(right, y, bottom);
+    }
+
+    /// 水平の区切り線を引く（枠線と `├` `┤` で接続する）
+    pub fn divider(&mut self, x: usize, y: usize, width: usize) {
+        self.hline(x, x + width - 1, y);
+    }
+
+    fn connect(&mut self, x: usize, y: usize, direction: u8) {
+        if x < self.width && y < self.height {
+            self.lines[y][x] |= direction;
+        }
+    }
+
+    /// 各行を文字列に変換する（末尾の空白は取り除く）
+    pub fn into_lines(self) -> Vec<String> {
+        let mut output = Vec::with_capacity(self.height);
+        for y in 0..self.height {
+            let mut row = String::new();
+            for x in 0..self.width {
+                match self.text[y][x] {
+                    Some('\0') => {}
+                    Some(c) => row.push(c),
+                    None => row.push(line_char(self.lines[y][x])),
+                }
+            }
+            output.push(row.trim_end().to_string());
+        }
+        output
+    }
+}
+
+/// 区画に分かれたボックスを左から右へ並べて描画する
+///
+/// 幅が [`MAX_WIDTH`] を超える場合は次の段に折り返す。
+///
+/// # 引数
+/// * `boxes` - ボックスごとの区画の一覧（区画は行の一覧、空の区画は区切り線のみ）
+///
+/// # 戻り値
+/// * `Vec<String>` - 描画結果の各行
+pub fn render_boxes(boxes: &[Vec<Vec<String>>]) -> Vec<String> {
+    let boxes: Vec<Vec<Vec<String>>> = boxes
+        .iter()
+        .map(|sections| {
+            sections
+                .iter()
+                .map(|lines| {
+                    lines
+                        .iter()
+                        .map(|line| truncate(line, MAX_BOX_TEXT_WIDTH))
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+    let sizes: Vec<(usize, usize)> = boxes
+        .iter()
+        .map(|sections| {
+            let width = sections
+                .iter()
+                .flatten()
+                .map(|line| text_width(line))
+                .max()
+                .unwrap_or(0)
+                + 4;
+            let height = sections.iter().map(Vec::len).sum::<usize>() + sections.len() + 1;
+            (width, height)
+        })
+        .collect();
+
+    // 段ごとに並べるボックスのインデックス
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    let mut row_width = 0;
+    for (index, (width, _)) in sizes.iter().enumerate() {
+        match rows.last_mut() {
+            Some(row) if row_width + 2 + width <= MAX_WIDTH => {
+                row.push(index);
+                row_width += 2 + width;
+            }
+            _ => {
+                rows.push(vec![index]);
+                row_width = *width;
+            }
+        }
+    }
+
+    let mut output = Vec::new();
+    for row in rows {
+        let width = row.iter().map(|&i| sizes[i].0 + 2).sum::<usize>();
+        let height = row.iter().map(|&i| sizes[i].1).max().unwrap_or(0);
+        let mut canvas = Canvas::new(width, height);
+        let mut x = 0;
+        for index in row {
+            let (box_width, box_height) = sizes[index];
+            canvas.rect(x, 0, box_width, box_height);
+            let mut y = 1;
+            for (section, lines) in boxes[index].iter().enumerate() {
+                if section > 0 {
+                    canvas.divider(x, y, box_width);
+                    y += 1;
+                }
+                for line in lines {
+                    canvas.put_str(x + 2, y, line);
+                    y += 1;
+                }
+            }
+            x += box_width + 2;
+        }
+        if !output.is_empty() {
+            output.push(String::new());
+        }
+        output.extend(canvas.into_lines());
+    }
+    output
+}
+
+/// 接続方向の組み合わせを罫線文字に変換する
+fn line_char(mask: u8) -> char {
+    match mask {
+        0 => ' ',
+        m if m == LEFT | RIGHT || m == LEFT || m == RIGHT => '─',
+        m if m == UP | DOWN || m == UP || m == DOWN => '│',
+        m if m == DOWN | RIGHT => '┌',
+        m if m == DOWN | LEFT => '┐',
+        m if m == UP | RIGHT => '└',
+        m if m == UP | LEFT => '┘',
+        m if m == UP | DOWN | RIGHT => '├',
+        m if m == UP | DOWN | LEFT => '┤',
+        m if m == LEFT | RIGHT | DOWN => '┬',
+        m if m == LEFT | RIGHT | UP => '┴',
+        _ => '┼',
+    }
+}
+
+/// 文字列の表示幅
+pub fn text_width(s: &str) -> usize {
+    s.width()
+}
+
+/// 表示幅が `max` を超える場合は末尾を `…` に置き換えて切り詰める
+pub fn truncate(s: &str, max: usize) -> String {
+    if text_width(s) <= max {
+        return s.to_string();
+    }
+    let mut result = String::new();
+    let mut width = 0;
+    for c in s.chars() {
+        let w = c.width().unwrap_or(0);
+        if width + w + 1 > max {
+            break;
+        }
+        result.push(c);
+        width += w;
+    }
+    result.push('…');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_with_divider_and_junction() {
+        let mut canvas = Canvas::new(6, 4);
+        canvas.rect(0, 0, 6, 4);
+        canvas.divider(0, 2, 6);
+        canvas.put_str(1, 1, "ab");
+
+        assert_eq!(
+            canvas.into_lines(),
+            vec!["┌────┐", "│ab  │", "├────┤", "└────┘"]
+        );
+    }
+
+    #[test]
+    fn test_crossing_lines() {
+        let mut canvas = Canvas::new(3, 3);
+        canvas.hline(0, 2, 1);
+        canvas.vline(1, 0, 2);
+
+        assert_eq!(canvas.into_lines(), vec![" │", "─┼─", " │"]);
+    }
+
+    #[test]
+    fn test_render_boxes() {
+        let boxes = vec![
+            vec![
+                vec!["User".to_string()],
+                vec!["id".to_string(), "name".to_string()],
+            ],
+            vec![vec!["Order".to_string()], vec![]],
+        ];
+
+        assert_eq!(
+            render_boxes(&boxes),
+            vec![
+                "┌──────┐  ┌───────┐",
+                "│ User │  │ Order │",
+                "├──────┤  ├───────┤",
+                "│ id   │  └───────┘",
+                "│ name │",
+                "└──────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wide_characters_and_truncate() {
+        let mut canvas = Canvas::new(6, 1);
+        canvas.put_str(0, 0, "設計書です");
+
+        assert_eq!(canvas.into_lines(), vec!["設計書"]);
+        assert_eq!(truncate("abcdef", 4), "abc…");
+        assert_eq!(truncate("abc", 4), "abc");
+    }
+}
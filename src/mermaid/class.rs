@@ -0,0 +1,227 @@
+use crate::mermaid::canvas::render_boxes;
+
+/// `classDiagram` ダイアグラム
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassDiagram {
+    pub classes: Vec<Class>,
+    pub relations: Vec<Relation>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Class {
+    pub name: String,
+    // `<<interface>>` などの注釈
+    pub annotation: Option<String>,
+    pub attributes: Vec<String>,
+    pub methods: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relation {
+    pub left: String,
+    pub right: String,
+    // 矢印記号（`◁──` など）
+    pub symbol: &'static str,
+    // 関係の種類（`inheritance` など）
+    pub kind: &'static str,
+    pub label: Option<String>,
+}
+
+/// Mermaid の関係記号と描画記号・種類の対応
+const RELATIONS: [(&str, &str, &str); 16] = [
+    ("<|--", "◁──", "inheritance"),
+    ("--|>", "──▷", "inheritance"),
+    ("<|..", "◁╌╌", "realization"),
+    ("..|>", "╌╌▷", "realization"),
+    ("*--", "◆──", "composition"),
+    ("--*", "──◆", "composition"),
+    ("o--", "◇──", "aggregation"),
+    ("--o", "──◇", "aggregation"),
+    ("<--", "◀──", "association"),
+    ("-->", "──▶", "association"),
+    ("<..", "◀╌╌", "dependency"),
+    ("..>", "╌╌▶", "dependency"),
+    ("--", "───", "link"),
+    ("..", "╌╌╌", "link"),
+    ("<-->", "◀─▶", "association"),
+    ("<..>", "◀╌▶", "dependency"),
+];
+
+/// クラス図のソースを解析する
+pub fn parse(source: &str) -> Option<ClassDiagram> {
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    if lines.next()? != "classDiagram" {
+        return None;
+    }
+
+    let mut diagram = ClassDiagram {
+        classes: Vec::new(),
+        relations: Vec::new(),
+    };
+    // メンバー定義ブロック内のクラス
+    let mut current: Option<usize> = None;
+
+    for line in lines {
+        if line.starts_with("%%") {
+            continue;
+        }
+        if let Some(index) = current {
+            if line == "}" {
+                current = None;
+            } else if line.starts_with("<<") {
+                diagram.classes[index].annotation = Some(line.to_string());
+            } else {
+                add_member(&mut diagram.classes[index], line);
+            }
+        } else if let Some(rest) = line.strip_prefix("class ") {
+            let (name, open) = match rest.strip_suffix('{') {
+                Some(name) => (name.trim(), true),
+                None => (rest.trim(), false),
+            };
+            let index = class_index(&mut diagram, name);
+            if open {
+                current = Some(index);
+            }
+        } else if let Some(relation) = parse_relation(line) {
+            class_index(&mut diagram, &relation.left);
+            class_index(&mut diagram, &relation.right);
+            diagram.relations.push(relation);
+        } else if let Some((name, member)) = line.split_once(':') {
+            let index = class_index(&mut diagram, name.trim());
+            add_member(&mut diagram.classes[index], member.trim());
+        } else if let Some(rest) = line.strip_prefix("<<")
+            && let Some((annotation, name)) = rest.split_once(">>")
+        {
+            let index = class_index(&mut diagram, name.trim());
+            diagram.classes[index].annotation = Some(format!("<<{}>>", annotation));
+        }
+    }
+
+    Some(diagram)
+}
+
+/// ジェネリクス表記（`~T~`）を `<T>` に置き換えたクラス名でインデックスを返す
+fn class_index(diagram: &mut ClassDiagram, name: &str) -> usize {
+    let name = display_generics(name);
+    match diagram.classes.iter().position(|c| c.name == name) {
+        Some(index) => index,
+        None => {
+            diagram.classes.push(Class {
+                name,
+                annotation: None,
+                attributes: Vec::new(),
+                methods: Vec::new(),
+            });
+            diagram.classes.len() - 1
+        }
+    }
+}
+
+fn display_generics(text: &str) -> String {
+    let mut result = String::new();
+    for (index, part) in text.split('~').enumerate() {
+        if index > 0 {
+            result.push(if index % 2 == 1 { '<' } else { '>' });
+        }
+        result.push_str(part);
+    }
+    result
+}
+
+/// `(` を含むメンバーはメソッド、それ以外は属性として追加する
+fn add_member(class: &mut Class, member: &str) {
+    let member = display_generics(member);
+    if member.contains('(') {
+        class.methods.push(member);
+    } else {
+        class.attributes.push(member);
+    }
+}
+
+/// `Animal <|-- Duck : label` 形式の関係を解析する
+fn parse_relation(line: &str) -> Option<Relation> {
+    let (relation, label) = match line.split_once(':') {
+        Some((relation, label)) => (relation, Some(label.trim().to_string())),
+        None => (line, None),
+    };
+    // 多重度（`"1"` など）は読み飛ばす
+    let tokens: Vec<&str> = relation
+        .split_whitespace()
+        .filter(|token| !token.starts_with('"'))
+        .collect();
+    let [left, operator, right] = tokens.as_slice() else {
+        return None;
+    };
+    let (_, symbol, kind) = RELATIONS.iter().find(|(op, _, _)| op == operator)?;
+
+    Some(Relation {
+        left: display_generics(left),
+        right: display_generics(right),
+        symbol,
+        kind,
+        label: label.filter(|label| !label.is_empty()),
+    })
+}
+
+/// クラスのボックス（名前・属性・メソッドの 3 区画）と関係の一覧を描画する
+pub fn render(diagram: &ClassDiagram) -> Vec<String> {
+    let boxes: Vec<Vec<Vec<String>>> = diagram
+        .classes
+        .iter()
+        .map(|class| {
+            let mut header: Vec<String> = class.annotation.iter().cloned().collect();
+            header.push(class.name.clone());
+            vec![header, class.attributes.clone(), class.methods.clone()]
+        })
+        .collect();
+    let mut lines = render_boxes(&boxes);
+
+    if !diagram.relations.is_empty() {
+        lines.push(String::new());
+    }
+    for relation in &diagram.relations {
+        let mut line = format!(
+            "{} {} {} ({})",
+            relation.left, relation.symbol, relation.right, relation.kind
+        );
+        if let Some(label) = &relation.label {
+            line.push_str(&format!(" : {}", label));
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "classDiagram\n    Animal <|-- Duck\n    Animal : +int age\n    Animal : +isMammal() bool\n    class Duck {\n        <<entity>>\n        +String beakColor\n        +swim()\n    }\n    class List~T~\n    Duck \"1\" *-- \"many\" Egg : lays";
+
+    #[test]
+    fn test_parse_class_diagram() {
+        let diagram = parse(SOURCE).unwrap();
+
+        let names: Vec<&str> = diagram.classes.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Animal", "Duck", "List<T>", "Egg"]);
+        assert_eq!(diagram.classes[0].attributes, vec!["+int age"]);
+        assert_eq!(diagram.classes[0].methods, vec!["+isMammal() bool"]);
+        assert_eq!(diagram.classes[1].annotation.as_deref(), Some("<<entity>>"));
+        assert_eq!(diagram.relations[0].kind, "inheritance");
+        assert_eq!(diagram.relations[1].kind, "composition");
+        assert_eq!(diagram.relations[1].label.as_deref(), Some("lays"));
+    }
+
+    #[test]
+    fn test_render_class_diagram() {
+        let lines = render(&parse(SOURCE).unwrap());
+
+        assert_eq!(lines[0].matches('┌').count(), 4);
+        assert!(lines[1].starts_with("│ Animal           │  │ <<entity>>"));
+        assert!(lines.contains(&"Animal ◁── Duck (inheritance)".to_string()));
+        assert!(lines.contains(&"Duck ◆── Egg (composition) : lays".to_string()));
+    }
+}
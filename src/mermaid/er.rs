@@ -0,0 +1,211 @@
+use crate::mermaid::canvas::render_boxes;
+
+/// `erDiagram` ダイアグラム
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErDiagram {
+    pub entities: Vec<Entity>,
+    pub relationships: Vec<Relationship>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entity {
+    pub name: String,
+    // `型 名前 キー` 形式の属性行
+    pub attributes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Relationship {
+    pub from: String,
+    pub to: String,
+    // `1` / `0..1` / `1..*` / `0..*`
+    pub from_cardinality: &'static str,
+    pub to_cardinality: &'static str,
+    pub label: String,
+    // `--`（識別関係）なら true、`..` なら false
+    pub identifying: bool,
+}
+
+/// ER 図のソースを解析する
+pub fn parse(source: &str) -> Option<ErDiagram> {
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    if lines.next()? != "erDiagram" {
+        return None;
+    }
+
+    let mut diagram = ErDiagram {
+        entities: Vec::new(),
+        relationships: Vec::new(),
+    };
+    // 属性ブロック内のエンティティ
+    let mut current: Option<usize> = None;
+
+    for line in lines {
+        if line.starts_with("%%") {
+            continue;
+        }
+        if let Some(index) = current {
+            if line == "}" {
+                current = None;
+            } else {
+                // 末尾の `"コメント"` は表示しない
+                let attribute = line.split('"').next().unwrap_or(line).trim();
+                diagram.entities[index]
+                    .attributes
+                    .push(attribute.to_string());
+            }
+        } else if let Some(name) = line.strip_suffix('{') {
+            current = Some(entity_index(&mut diagram, name.trim()));
+        } else if let Some(relationship) = parse_relationship(line) {
+            entity_index(&mut diagram, &relationship.from);
+            entity_index(&mut diagram, &relationship.to);
+            diagram.relationships.push(relationship);
+        } else if !line.contains(char::is_whitespace) {
+            entity_index(&mut diagram, line);
+        }
+    }
+
+    Some(diagram)
+}
+
+fn entity_index(diagram: &mut ErDiagram, name: &str) -> usize {
+    match diagram.entities.iter().position(|e| e.name == name) {
+        Some(index) => index,
+        None => {
+            diagram.entities.push(Entity {
+                name: name.to_string(),
+                attributes: Vec::new(),
+            });
+            diagram.entities.len() - 1
+        }
+    }
+}
+
+/// `CUSTOMER ||--o{ ORDER : places` 形式の関係を解析する
+fn parse_relationship(line: &str) -> Option<Relationship> {
+    let (relation, label) = line.split_once(':')?;
+    let mut tokens = relation.split_whitespace();
+    let (from, operator, to) = (tokens.next()?, tokens.next()?, tokens.next()?);
+    // 非 ASCII の記号を文字境界の途中で切らない
+    if tokens.next().is_some() || !operator.is_ascii() || operator.len() < 6 {
+        return None;
+    }
+
+    let identifying = match &operator[2..4] {
+        "--" => true,
+        ".." => false,
+        _ => return None,
+    };
+
+    Some(Relationship {
+        from: from.to_string(),
+        to: to.to_string(),
+        from_cardinality: cardinality(&operator[..2])?,
+        to_cardinality: cardinality(&operator[4..])?,
+        label: label.trim().trim_matches('"').to_string(),
+        identifying,
+    })
+}
+
+/// カーディナリティ記号を多重度の表記に変換する
+fn cardinality(symbol: &str) -> Option<&'static str> {
+    match symbol {
+        "||" => Some("1"),
+        "|o" | "o|" => Some("0..1"),
+        "}|" | "|{" => Some("1..*"),
+        "}o" | "o{" => Some("0..*"),
+        _ => None,
+    }
+}
+
+/// エンティティのボックスと関係の一覧を描画する
+pub fn render(diagram: &ErDiagram) -> Vec<String> {
+    let boxes: Vec<Vec<Vec<String>>> = diagram
+        .entities
+        .iter()
+        .map(|entity| {
+            let mut sections = vec![vec![entity.name.clone()]];
+            if !entity.attributes.is_empty() {
+                sections.push(entity.attributes.clone());
+            }
+            sections
+        })
+        .collect();
+    let mut lines = render_boxes(&boxes);
+
+    if !diagram.relationships.is_empty() {
+        lines.push(String::new());
+    }
+    for relationship in &diagram.relationships {
+        let line = if relationship.identifying {
+            "──"
+        } else {
+            "╌╌"
+        };
+        lines.push(format!(
+            "{} {} {}{}{} {} {} : {}",
+            relationship.from,
+            relationship.from_cardinality,
+            line,
+            line,
+            line,
+            relationship.to_cardinality,
+            relationship.to,
+            relationship.label
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "erDiagram\n    CUSTOMER ||--o{ ORDER : places\n    ORDER ||..|{ LINE_ITEM : \"contains\"\n    CUSTOMER {\n        string name PK \"display name\"\n        int age\n    }";
+
+    #[test]
+    fn test_parse_er_diagram() {
+        let diagram = parse(SOURCE).unwrap();
+
+        let names: Vec<&str> = diagram.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["CUSTOMER", "ORDER", "LINE_ITEM"]);
+        assert_eq!(
+            diagram.entities[0].attributes,
+            vec!["string name PK", "int age"]
+        );
+        assert_eq!(diagram.relationships[0].from_cardinality, "1");
+        assert_eq!(diagram.relationships[0].to_cardinality, "0..*");
+        assert!(diagram.relationships[0].identifying);
+        assert_eq!(diagram.relationships[1].to_cardinality, "1..*");
+        assert_eq!(diagram.relationships[1].label, "contains");
+        assert!(!diagram.relationships[1].identifying);
+    }
+
+    #[test]
+    fn test_render_er_diagram() {
+        let lines = render(&parse(SOURCE).unwrap());
+
+        assert_eq!(lines[0], "┌────────────────┐  ┌───────┐  ┌───────────┐");
+        assert_eq!(lines[1], "│ CUSTOMER       │  │ ORDER │  │ LINE_ITEM │");
+        assert_eq!(lines[3], "│ string name PK │");
+        assert!(lines.contains(&"CUSTOMER 1 ────── 0..* ORDER : places".to_string()));
+        assert!(lines.contains(&"ORDER 1 ╌╌╌╌╌╌ 1..* LINE_ITEM : contains".to_string()));
+    }
+
+    #[test]
+    fn test_parse_er_diagram_with_cjk_names() {
+        let diagram =
+            parse("erDiagram\n  顧客 ||--o{ 注文 : 発注する\n  顧客 注文する 注文 : places")
+                .unwrap();
+
+        // 関係記号が非 ASCII の行は関係として扱わず、パニックもしない
+        let names: Vec<&str> = diagram.entities.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["顧客", "注文"]);
+        assert_eq!(diagram.relationships.len(), 1);
+        assert_eq!(diagram.relationships[0].label, "発注する");
+        assert!(render(&diagram).contains(&"顧客 1 ────── 0..* 注文 : 発注する".to_string()));
+    }
+}
@@ -0,0 +1,615 @@
+use std::collections::HashMap;
+
+use crate::mermaid::canvas::{Canvas, text_width, truncate};
+
+/// ノードラベルの最大表示幅
+const MAX_LABEL_WIDTH: usize = 30;
+/// 同じ階層のノード間の間隔
+const NODE_GAP: usize = 3;
+
+/// `flowchart` / `graph` ダイアグラム
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flowchart {
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub id: String,
+    pub label: String,
+    // `{...}` の分岐ノード
+    pub decision: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub label: Option<String>,
+    // 矢印の有無（`-->` は true、`---` は false）
+    pub arrow: bool,
+}
+
+/// フローチャートのソースを解析する
+///
+/// `A[Label] -->|text| B{Label} --> C & D` のような連結・分岐を含む辺に対応する。
+/// `subgraph` / `style` / `classDef` などの装飾行は無視する。
+pub fn parse(source: &str) -> Option<Flowchart> {
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    let header = lines.next()?;
+    if !(header.starts_with("flowchart") || header.starts_with("graph")) {
+        return None;
+    }
+
+    let mut chart = Flowchart {
+        nodes: Vec::new(),
+        edges: Vec::new(),
+    };
+
+    for line in lines {
+        for statement in line.split(';').map(str::trim) {
+            if statement.is_empty() || is_ignored_statement(statement) {
+                continue;
+            }
+            parse_statement(statement, &mut chart);
+        }
+    }
+
+    Some(chart)
+}
+
+/// レイアウトに関係しない行か判定する
+fn is_ignored_statement(statement: &str) -> bool {
+    statement.starts_with("%%")
+        || statement == "end"
+        || [
+            "subgraph ",
+            "style ",
+            "classDef ",
+            "class ",
+            "linkStyle ",
+            "click ",
+            "direction ",
+        ]
+        .iter()
+        .any(|prefix| statement.starts_with(prefix))
+}
+
+/// `A --> B --> C` のような 1 文を解析してノードと辺を追加する
+fn parse_statement(statement: &str, chart: &mut Flowchart) {
+    let mut rest = statement;
+    let Some((mut previous, remaining)) = parse_node_group(rest, chart) else {
+        return;
+    };
+    rest = remaining;
+
+    while let Some((arrow, label, remaining)) = parse_link(rest) {
+        let Some((targets, remaining)) = parse_node_group(remaining, chart) else {
+            return;
+        };
+        for &from in &previous {
+            for &to in &targets {
+                chart.edges.push(Edge {
+                    from,
+                    to,
+                    label: label.clone(),
+                    arrow,
+                });
+            }
+        }
+        previous = targets;
+        rest = remaining;
+    }
+}
+
+/// `A & B` のような `&` 区切りのノード群を解析する
+fn parse_node_group<'a>(text: &'a str, chart: &mut Flowchart) -> Option<(Vec<usize>, &'a str)> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+    loop {
+        let (node, remaining) = parse_node(rest, chart)?;
+        nodes.push(node);
+        rest = remaining.trim_start();
+        match rest.strip_prefix('&') {
+            Some(remaining) => rest = remaining,
+            None => return Some((nodes, rest)),
+        }
+    }
+}
+
+/// ノード定義（`id` と任意の形状付きラベル）を解析してノードのインデックスを返す
+fn parse_node<'a>(text: &'a str, chart: &mut Flowchart) -> Option<(usize, &'a str)> {
+    let text = text.trim_start();
+    let id_end = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    if id_end == 0 {
+        return None;
+    }
+    let (id, mut rest) = text.split_at(id_end);
+
+    const SHAPES: [(&str, &str); 12] = [
+        ("(((", ")))"),
+        ("((", "))"),
+        ("([", "])"),
+        ("[(", ")]"),
+        ("[[", "]]"),
+        ("{{", "}}"),
+        ("[/", "/]"),
+        ("[\\", "\\]"),
+        ("[", "]"),
+        ("(", ")"),
+        ("{", "}"),
+        (">", "]"),
+    ];
+
+    let mut label = None;
+    let mut decision = false;
+    for (open, close) in SHAPES {
+        if let Some(inner) = rest.strip_prefix(open)
+            && let Some(end) = inner.find(close)
+        {
+            label = Some(inner[..end].trim().trim_matches('"').to_string());
+            decision = open == "{";
+            rest = &inner[end + close.len()..];
+            break;
+        }
+    }
+
+    let index = match chart.nodes.iter().position(|node| node.id == id) {
+        Some(index) => {
+            if let Some(label) = label {
+                chart.nodes[index].label = label;
+                chart.nodes[index].decision = decision;
+            }
+            index
+        }
+        None => {
+            chart.nodes.push(Node {
+                id: id.to_string(),
+                label: label.unwrap_or_else(|| id.to_string()),
+                decision,
+            });
+            chart.nodes.len() - 1
+        }
+    };
+
+    Some((index, rest))
+}
+
+/// 辺の記号（`-->`, `---`, `-.->`, `==>`, `-- text -->`, `-->|text|` など）を解析する
+///
+/// # 戻り値
+/// * `Option<(bool, Option<String>, &str)>` - (矢印の有無, ラベル, 残りの文字列)
+fn parse_link(text: &str) -> Option<(bool, Option<String>, &str)> {
+    let text = text.trim_start();
+    let body = text.strip_prefix('<').unwrap_or(text);
+    let run = body
+        .find(|c: char| !matches!(c, '-' | '=' | '.' | '~'))
+        .unwrap_or(body.len());
+    if run < 2 {
+        return None;
+    }
+    let (operator, mut rest) = body.split_at(run);
+    let mut label = None;
+    let mut arrow = false;
+
+    if let Some(remaining) = rest.strip_prefix('>') {
+        arrow = true;
+        rest = remaining;
+    } else if matches!(operator, "--" | "==" | "-.") && rest.starts_with(char::is_whitespace) {
+        // `-- text -->` 形式のインラインラベル
+        let closing = ["-->", "---", "==>", "===", ".->", "-.-"]
+            .iter()
+            .filter_map(|close| rest.find(close).map(|pos| (pos, *close)))
+            .min_by_key(|(pos, _)| *pos)?;
+        label = Some(rest[..closing.0].trim().to_string());
+        arrow = closing.1.ends_with('>');
+        rest = &rest[closing.0 + closing.1.len()..];
+    } else if let Some(remaining) = rest.strip_prefix(['o', 'x'])
+        && remaining.starts_with(char::is_whitespace)
+    {
+        arrow = true;
+        rest = remaining;
+    }
+
+    let rest = rest.trim_start();
+    if let Some(inner) = rest.strip_prefix('|')
+        && let Some(end) = inner.find('|')
+    {
+        label = Some(inner[..end].trim().to_string());
+        return Some((arrow, label.filter(|l| !l.is_empty()), &inner[end + 1..]));
+    }
+
+    Some((arrow, label.filter(|l| !l.is_empty()), rest))
+}
+
+/// レイアウト上の配置単位（ノード、または複数階層をまたぐ辺の中継点）
+struct Item {
+    node: Option<usize>,
+    width: usize,
+    x: usize,
+}
+
+/// 隣接する階層間の辺の区間
+struct Segment {
+    from: usize,
+    to: usize,
+    arrow: bool,
+    label: Option<String>,
+}
+
+/// フローチャートを上から下への階層レイアウトで描画する
+///
+/// 向き（`LR` など）に関わらず上から下に描画する。循環を作る逆向きの辺は
+/// 図の下に一覧として表示する。
+pub fn render(chart: &Flowchart) -> Vec<String> {
+    if chart.nodes.is_empty() {
+        return Vec::new();
+    }
+
+    let labels: Vec<String> = chart
+        .nodes
+        .iter()
+        .map(|node| {
+            let label = truncate(&node.label, MAX_LABEL_WIDTH);
+            if node.decision {
+                format!("◇ {}", label)
+            } else {
+                label
+            }
+        })
+        .collect();
+
+    let back_edges = find_back_edges(chart);
+    let ranks = assign_ranks(chart, &back_edges);
+    let layer_count = ranks.iter().max().map_or(0, |max| max + 1);
+
+    // 階層ごとの配置単位
+    let mut items: Vec<Item> = Vec::new();
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    let mut item_layer: Vec<usize> = Vec::new();
+    let mut node_item: HashMap<usize, usize> = HashMap::new();
+
+    for (node, &rank) in ranks.iter().enumerate() {
+        node_item.insert(node, items.len());
+        layers[rank].push(items.len());
+        item_layer.push(rank);
+        items.push(Item {
+            node: Some(node),
+            width: text_width(&labels[node]) + 4,
+            x: 0,
+        });
+    }
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for (index, edge) in chart.edges.iter().enumerate() {
+        if back_edges.contains(&index) {
+            continue;
+        }
+        let mut previous = node_item[&edge.from];
+        let (first, last) = (ranks[edge.from] + 1, ranks[edge.to]);
+        for (layer, members) in layers.iter_mut().enumerate().take(last).skip(first) {
+            let dummy = items.len();
+            members.push(dummy);
+            item_layer.push(layer);
+            items.push(Item {
+                node: None,
+                width: 1,
+                x: 0,
+            });
+            segments.push(Segment {
+                from: previous,
+                to: dummy,
+                arrow: false,
+                label: None,
+            });
+            previous = dummy;
+        }
+        segments.push(Segment {
+            from: previous,
+            to: node_item[&edge.to],
+            arrow: edge.arrow,
+            label: edge.label.clone(),
+        });
+    }
+
+    order_layers(&mut layers, &segments);
+
+    // 水平位置
+    let layer_widths: Vec<usize> = layers
+        .iter()
+        .map(|layer| {
+            let widths: usize = layer.iter().map(|&item| items[item].width).sum();
+            widths + NODE_GAP * layer.len().saturating_sub(1)
+        })
+        .collect();
+    let width = layer_widths.iter().copied().max().unwrap_or(0);
+    for (layer, layer_width) in layers.iter().zip(&layer_widths) {
+        let mut x = (width - layer_width) / 2;
+        for &item in layer {
+            items[item].x = x;
+            x += items[item].width + NODE_GAP;
+        }
+    }
+    let center = |item: &Item| item.x + item.width / 2;
+
+    // 垂直位置（階層間には始点ごとに 1 行 + 矢印行を確保）
+    let mut layer_y = vec![0; layer_count];
+    let mut sources_by_layer: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    for segment in &segments {
+        let layer = item_layer[segment.from];
+        if !sources_by_layer[layer].contains(&segment.from) {
+            sources_by_layer[layer].push(segment.from);
+        }
+    }
+    for layer in 1..layer_count {
+        let sources = sources_by_layer[layer - 1].len();
+        layer_y[layer] = layer_y[layer - 1] + 3 + if sources > 0 { sources + 1 } else { 1 };
+    }
+    let height = layer_y.last().copied().unwrap_or(0) + 3;
+
+    // 各線分の経路（始点ごとの行に水平線を引く）
+    let routes: Vec<(usize, usize, usize, usize)> = segments
+        .iter()
+        .map(|segment| {
+            let layer = item_layer[segment.from];
+            let row = layer_y[layer]
+                + 3
+                + sources_by_layer[layer]
+                    .iter()
+                    .position(|&item| item == segment.from)
+                    .unwrap_or(0);
+            (
+                center(&items[segment.from]),
+                center(&items[segment.to]),
+                row,
+                layer_y[layer + 1],
+            )
+        })
+        .collect();
+
+    // ラベルは線分自身の行に置く（水平線に収まらなければ到達側の縦線の横）
+    let placements: Vec<(usize, usize, &str)> = segments
+        .iter()
+        .zip(&routes)
+        .filter_map(|(segment, &(sx, tx, row, _))| {
+            let label = segment.label.as_deref()?;
+            let label_width = text_width(label);
+            let (start, end) = (sx.min(tx), sx.max(tx));
+            let gap = (end - start).saturating_sub(1);
+            let x = if sx != tx && gap >= label_width {
+                start + 1 + (gap - label_width) / 2
+            } else if tx < sx && tx > label_width {
+                tx - label_width - 1
+            } else {
+                end + 2
+            };
+            Some((x, row, label))
+        })
+        .collect();
+    let width = placements
+        .iter()
+        .map(|&(x, _, label)| x + text_width(label))
+        .fold(width, usize::max);
+
+    let mut canvas = Canvas::new(width, height);
+    for (index, item) in items.iter().enumerate() {
+        let y = layer_y[item_layer[index]];
+        match item.node {
+            Some(node) => {
+                canvas.rect(item.x, y, item.width, 3);
+                canvas.put_str(item.x + 2, y + 1, &labels[node]);
+            }
+            None => canvas.vline(item.x, y, y + 2),
+        }
+    }
+
+    for (segment, &(sx, tx, row, target_top)) in segments.iter().zip(&routes) {
+        canvas.vline(sx, layer_y[item_layer[segment.from]] + 2, row);
+        canvas.hline(sx, tx, row);
+        if segment.arrow {
+            canvas.vline(tx, row, target_top - 1);
+            canvas.put_char(tx, target_top - 1, '▼');
+        } else {
+            canvas.vline(tx, row, target_top);
+        }
+    }
+    for (x, row, label) in placements {
+        canvas.put_str(x, row, label);
+    }
+
+    let mut lines = canvas.into_lines();
+    for &index in &back_edges {
+        let edge = &chart.edges[index];
+        let mut line = format!(
+            "↺ {} ──▶ {}",
+            chart.nodes[edge.from].label, chart.nodes[edge.to].label
+        );
+        if let Some(label) = &edge.label {
+            line.push_str(&format!(" ({})", label));
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// 深さ優先探索で循環を作る辺（自己ループを含む）を求める
+fn find_back_edges(chart: &Flowchart) -> Vec<usize> {
+    fn visit(node: usize, chart: &Flowchart, state: &mut [u8], back: &mut Vec<usize>) {
+        state[node] = 1;
+        for (index, edge) in chart.edges.iter().enumerate() {
+            if edge.from != node {
+                continue;
+            }
+            match state[edge.to] {
+                0 => visit(edge.to, chart, state, back),
+                1 => back.push(index),
+                _ => {}
+            }
+        }
+        state[node] = 2;
+    }
+
+    let mut state = vec![0u8; chart.nodes.len()];
+    let mut back = Vec::new();
+    for node in 0..chart.nodes.len() {
+        if state[node] == 0 {
+            visit(node, chart, &mut state, &mut back);
+        }
+    }
+    back.sort_unstable();
+    back
+}
+
+/// 逆向きの辺を除いたグラフで最長経路に基づく階層を割り当てる
+fn assign_ranks(chart: &Flowchart, back_edges: &[usize]) -> Vec<usize> {
+    let n = chart.nodes.len();
+    let forward: Vec<&Edge> = chart
+        .edges
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| !back_edges.contains(index))
+        .map(|(_, edge)| edge)
+        .collect();
+
+    let mut in_degree = vec![0; n];
+    for edge in &forward {
+        in_degree[edge.to] += 1;
+    }
+    let mut queue: Vec<usize> = (0..n).filter(|&node| in_degree[node] == 0).collect();
+    let mut ranks = vec![0; n];
+    let mut head = 0;
+    while head < queue.len() {
+        let node = queue[head];
+        head += 1;
+        for edge in forward.iter().filter(|edge| edge.from == node) {
+            ranks[edge.to] = ranks[edge.to].max(ranks[node] + 1);
+            in_degree[edge.to] -= 1;
+            if in_degree[edge.to] == 0 {
+                queue.push(edge.to);
+            }
+        }
+    }
+    ranks
+}
+
+/// 前の階層の接続元の平均位置で各階層を並べ替え、辺の交差を減らす
+fn order_layers(layers: &mut [Vec<usize>], segments: &[Segment]) {
+    for layer in 1..layers.len() {
+        let (above, below) = layers.split_at_mut(layer);
+        let previous = &above[layer - 1];
+        let current = &mut below[0];
+
+        let key = |item: usize, fallback: usize| {
+            let positions: Vec<usize> = segments
+                .iter()
+                .filter(|segment| segment.to == item)
+                .filter_map(|segment| previous.iter().position(|&p| p == segment.from))
+                .collect();
+            if positions.is_empty() {
+                fallback as f64
+            } else {
+                positions.iter().sum::<usize>() as f64 / positions.len() as f64
+            }
+        };
+        let mut keyed: Vec<(f64, usize)> = current
+            .iter()
+            .enumerate()
+            .map(|(index, &item)| (key(item, index), item))
+            .collect();
+        keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        *current = keyed.into_iter().map(|(_, item)| item).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_flowchart() {
+        let chart = parse(
+            "flowchart TD\n    A[Start] --> B{Valid?}\n    B -->|yes| C(Save)\n    B -- no --> D[Reject]; D --> A\n    C & D --> E\n    style A fill:#f9f",
+        )
+        .unwrap();
+
+        let labels: Vec<&str> = chart.nodes.iter().map(|n| n.label.as_str()).collect();
+        assert_eq!(labels, vec!["Start", "Valid?", "Save", "Reject", "E"]);
+        assert!(chart.nodes[1].decision);
+        assert_eq!(chart.edges.len(), 6);
+        assert_eq!(chart.edges[1].label.as_deref(), Some("yes"));
+        assert_eq!(chart.edges[2].label.as_deref(), Some("no"));
+        assert!(chart.edges[2].arrow);
+        assert_eq!((chart.edges[4].from, chart.edges[4].to), (2, 4));
+        assert_eq!((chart.edges[5].from, chart.edges[5].to), (3, 4));
+    }
+
+    #[test]
+    fn test_parse_rejects_other_diagrams() {
+        assert!(parse("sequenceDiagram\n A->>B: hi").is_none());
+    }
+
+    #[test]
+    fn test_render_simple_chain() {
+        let chart = parse("graph LR\n  A[Load] --> B[Save]").unwrap();
+
+        assert_eq!(
+            render(&chart),
+            vec![
+                "┌──────┐",
+                "│ Load │",
+                "└───┬──┘",
+                "    │",
+                "    ▼",
+                "┌──────┐",
+                "│ Save │",
+                "└──────┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_branch_and_back_edge() {
+        let chart = parse("flowchart TD\n  A --> B\n  A --> C\n  C --> A").unwrap();
+
+        let lines = render(&chart);
+
+        assert_eq!(lines[0], "    ┌───┐");
+        assert_eq!(lines[2], "    └─┬─┘");
+        assert_eq!(lines[3], "  ┌───┴───┐");
+        assert_eq!(lines[4], "  ▼       ▼");
+        assert_eq!(lines[5], "┌───┐   ┌───┐");
+        assert_eq!(lines.last().unwrap(), "↺ C ──▶ A");
+    }
+
+    #[test]
+    fn test_render_long_edge_passes_through_layer() {
+        let chart = parse("flowchart TD\n  A --> B --> C\n  A --> C").unwrap();
+
+        let lines = render(&chart);
+
+        // B の横を中継点の縦線が通る
+        assert!(lines.iter().any(|line| line.contains("│ B │   │")));
+        assert_eq!(lines.iter().filter(|line| line.contains('▼')).count(), 2);
+    }
+
+    #[test]
+    fn test_render_labels_into_same_target() {
+        let chart =
+            parse("flowchart TD\n  S --> A & B\n  A -->|yes| E\n  B -->|no| E\n  S -->|skip| E")
+                .unwrap();
+
+        let lines = render(&chart);
+
+        // 同じノードへ入る線のラベルがそれぞれの線の行に残る
+        assert_eq!(lines[8], "  └─yes─┐ │     │");
+        assert_eq!(lines[9], "     no ├─┘     │");
+        assert_eq!(lines[10], "        ├─skip──┘");
+        assert_eq!(lines[11], "        ▼");
+    }
+}
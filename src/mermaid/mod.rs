@@ -0,0 +1,129 @@
+mod canvas;
+pub mod class;
+pub mod er;
+pub mod flowchart;
+pub mod sequence;
+
+/// 表示用に展開したドキュメントの 1 行
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentLine {
+    pub text: String,
+    // 元の Markdown での行番号（1 始まり、図の行はフェンス開始行）
+    pub source_line: usize,
+    // Mermaid 図を描画した行か
+    pub diagram: bool,
+}
+
+/// Mermaid ソースを罫線で描画する
+///
+/// 先頭行の種類で `flowchart` / `graph`、`sequenceDiagram`、`erDiagram`、
+/// `classDiagram` を判別する。
+///
+/// # 戻り値
+/// * `Option<Vec<String>>` - 描画結果（未対応の図や解析できない場合は None）
+pub fn render_diagram(source: &str) -> Option<Vec<String>> {
+    let kind = source
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("%%"))?;
+    let source = source.trim_start();
+
+    let lines = if kind.starts_with("flowchart") || kind.starts_with("graph") {
+        flowchart::render(&flowchart::parse(source)?)
+    } else if kind == "sequenceDiagram" {
+        sequence::render(&sequence::parse(source)?)
+    } else if kind == "erDiagram" {
+        er::render(&er::parse(source)?)
+    } else if kind == "classDiagram" {
+        class::render(&class::parse(source)?)
+    } else {
+        return None;
+    };
+
+    (!lines.is_empty()).then_some(lines)
+}
+
+/// Markdown 内の ```` ```mermaid ```` コードブロックを描画結果に置き換える
+///
+/// 未対応の図や閉じられていないブロックは元のソースのまま残す。
+///
+/// # 引数
+/// * `content` - Markdown テキスト
+///
+/// # 戻り値
+/// * `Vec<DocumentLine>` - 表示する行の一覧
+pub fn expand_mermaid(content: &str) -> Vec<DocumentLine> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut output = Vec::with_capacity(lines.len());
+    let mut index = 0;
+
+    while index < lines.len() {
+        let trimmed = lines[index].trim_start();
+        let fence = ["```", "~~~"]
+            .into_iter()
+            .find(|fence| trimmed.strip_prefix(fence).map(str::trim) == Some("mermaid"));
+
+        if let Some(fence) = fence
+            && let Some(length) = lines[index + 1..]
+                .iter()
+                .position(|line| line.trim_start().starts_with(fence))
+            && let Some(diagram) = render_diagram(&lines[index + 1..index + 1 + length].join("\n"))
+        {
+            output.extend(diagram.into_iter().map(|text| DocumentLine {
+                text,
+                source_line: index + 1,
+                diagram: true,
+            }));
+            index += length + 2;
+            continue;
+        }
+
+        output.push(DocumentLine {
+            text: lines[index].to_string(),
+            source_line: index + 1,
+            diagram: false,
+        });
+        index += 1;
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_mermaid_replaces_supported_blocks() {
+        let content = "# Design\n\n```mermaid\ngraph TD\n  A --> B\n```\n\n```mermaid\npie title Pets\n```\nafter";
+
+        let lines = expand_mermaid(content);
+
+        assert_eq!(lines[0].text, "# Design");
+        assert!(lines[2].diagram);
+        assert_eq!(lines[2].text, "┌───┐");
+        assert_eq!(lines[2].source_line, 3);
+        let diagram_lines = lines.iter().filter(|line| line.diagram).count();
+        assert_eq!(diagram_lines, 8);
+        // 未対応の図（pie）はソースのまま残る
+        assert!(lines.iter().any(|line| line.text == "pie title Pets"));
+        let last = lines.last().unwrap();
+        assert_eq!((last.text.as_str(), last.source_line), ("after", 11));
+    }
+
+    #[test]
+    fn test_expand_mermaid_keeps_unclosed_block() {
+        let lines = expand_mermaid("```mermaid\ngraph TD\n  A --> B");
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| !line.diagram));
+    }
+
+    #[test]
+    fn test_render_diagram_dispatch() {
+        assert!(render_diagram("sequenceDiagram\n A->>B: hi").is_some());
+        assert!(render_diagram("erDiagram\n A ||--o{ B : has").is_some());
+        assert!(render_diagram("classDiagram\n A <|-- B").is_some());
+        assert!(render_diagram("gantt\n title x").is_none());
+    }
+}
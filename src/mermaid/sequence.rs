@@ -0,0 +1,457 @@
+use crate::mermaid::canvas::{Canvas, text_width, truncate};
+
+/// 参加者ラベル・メッセージの最大表示幅
+const MAX_TEXT_WIDTH: usize = 40;
+
+/// `sequenceDiagram` ダイアグラム
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceDiagram {
+    pub participants: Vec<Participant>,
+    pub events: Vec<SequenceEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Participant {
+    pub id: String,
+    pub label: String,
+}
+
+/// メッセージの矢じり
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArrowHead {
+    // `->` / `-->`
+    None,
+    // `->>` / `-->>` / `-)` / `--)`
+    Arrow,
+    // `-x` / `--x`
+    Cross,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceEvent {
+    Message {
+        from: usize,
+        to: usize,
+        text: String,
+        // `-->>` などの点線（応答）
+        dashed: bool,
+        head: ArrowHead,
+    },
+    Note {
+        placement: NotePlacement,
+        text: String,
+    },
+    // `loop` / `alt` / `else` / `opt` などのブロック境界
+    Block(String),
+    End,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NotePlacement {
+    LeftOf(usize),
+    RightOf(usize),
+    Over(usize, usize),
+}
+
+/// シーケンス図のソースを解析する
+pub fn parse(source: &str) -> Option<SequenceDiagram> {
+    let mut lines = source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty());
+    if lines.next()? != "sequenceDiagram" {
+        return None;
+    }
+
+    let mut diagram = SequenceDiagram {
+        participants: Vec::new(),
+        events: Vec::new(),
+    };
+
+    for line in lines {
+        if line.starts_with("%%") {
+            continue;
+        }
+        if let Some(rest) = line
+            .strip_prefix("participant ")
+            .or_else(|| line.strip_prefix("actor "))
+        {
+            let (id, label) = match rest.split_once(" as ") {
+                Some((id, label)) => (id.trim(), label.trim()),
+                None => (rest.trim(), rest.trim()),
+            };
+            let index = participant_index(&mut diagram, id);
+            diagram.participants[index].label = label.to_string();
+        } else if let Some(rest) = strip_prefix_ignore_case(line, "note ") {
+            if let Some(event) = parse_note(rest, &mut diagram) {
+                diagram.events.push(event);
+            }
+        } else if line == "end" {
+            diagram.events.push(SequenceEvent::End);
+        } else if let Some(keyword) = [
+            "loop", "alt", "else", "opt", "par", "and", "critical", "break", "rect",
+        ]
+        .into_iter()
+        .find(|keyword| {
+            line == *keyword
+                || line
+                    .strip_prefix(keyword)
+                    .is_some_and(|rest| rest.starts_with(' '))
+        }) {
+            let text = line[keyword.len()..].trim();
+            let label = if text.is_empty() {
+                keyword.to_string()
+            } else {
+                format!("{} {}", keyword, text)
+            };
+            diagram.events.push(SequenceEvent::Block(label));
+        } else if let Some(event) = parse_message(line, &mut diagram) {
+            diagram.events.push(event);
+        }
+    }
+
+    Some(diagram)
+}
+
+fn strip_prefix_ignore_case<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.get(..prefix.len())
+        .filter(|head| head.eq_ignore_ascii_case(prefix))
+        .map(|_| &line[prefix.len()..])
+}
+
+/// 参加者のインデックスを返す（未登録なら追加する）
+fn participant_index(diagram: &mut SequenceDiagram, id: &str) -> usize {
+    match diagram.participants.iter().position(|p| p.id == id) {
+        Some(index) => index,
+        None => {
+            diagram.participants.push(Participant {
+                id: id.to_string(),
+                label: id.to_string(),
+            });
+            diagram.participants.len() - 1
+        }
+    }
+}
+
+/// `A->>B: text` 形式のメッセージを解析する
+fn parse_message(line: &str, diagram: &mut SequenceDiagram) -> Option<SequenceEvent> {
+    let (arrow_part, text) = match line.split_once(':') {
+        Some((arrow_part, text)) => (arrow_part, text.trim()),
+        None => (line, ""),
+    };
+
+    const ARROWS: [(&str, bool, ArrowHead); 8] = [
+        ("-->>", true, ArrowHead::Arrow),
+        ("->>", false, ArrowHead::Arrow),
+        ("-->", true, ArrowHead::None),
+        ("--x", true, ArrowHead::Cross),
+        ("--)", true, ArrowHead::Arrow),
+        ("->", false, ArrowHead::None),
+        ("-x", false, ArrowHead::Cross),
+        ("-)", false, ArrowHead::Arrow),
+    ];
+    let (position, arrow, dashed, head) = ARROWS
+        .iter()
+        .filter_map(|(arrow, dashed, head)| {
+            arrow_part
+                .find(arrow)
+                .map(|position| (position, *arrow, *dashed, *head))
+        })
+        .min_by_key(|(position, arrow, _, _)| (*position, usize::MAX - arrow.len()))?;
+
+    let from = arrow_part[..position].trim();
+    let to = arrow_part[position + arrow.len()..]
+        .trim()
+        .trim_start_matches(['+', '-'])
+        .trim();
+    if from.is_empty() || to.is_empty() {
+        return None;
+    }
+
+    Some(SequenceEvent::Message {
+        from: participant_index(diagram, from),
+        to: participant_index(diagram, to),
+        text: text.to_string(),
+        dashed,
+        head,
+    })
+}
+
+/// `right of A: text` / `over A,B: text` 形式のノートを解析する
+fn parse_note(rest: &str, diagram: &mut SequenceDiagram) -> Option<SequenceEvent> {
+    let (target, text) = rest.split_once(':')?;
+    let target = target.trim();
+    let placement = if let Some(id) = target.strip_prefix("right of ") {
+        NotePlacement::RightOf(participant_index(diagram, id.trim()))
+    } else if let Some(id) = target.strip_prefix("left of ") {
+        NotePlacement::LeftOf(participant_index(diagram, id.trim()))
+    } else if let Some(ids) = target.strip_prefix("over ") {
+        let (first, last) = ids.split_once(',').unwrap_or((ids, ids));
+        let first = participant_index(diagram, first.trim());
+        let last = participant_index(diagram, last.trim());
+        NotePlacement::Over(first.min(last), first.max(last))
+    } else {
+        return None;
+    };
+
+    Some(SequenceEvent::Note {
+        placement,
+        text: text.trim().to_string(),
+    })
+}
+
+/// シーケンス図を参加者ごとのライフラインとメッセージの矢印で描画する
+pub fn render(diagram: &SequenceDiagram) -> Vec<String> {
+    if diagram.participants.is_empty() {
+        return Vec::new();
+    }
+
+    let labels: Vec<String> = diagram
+        .participants
+        .iter()
+        .map(|p| truncate(&p.label, MAX_TEXT_WIDTH))
+        .collect();
+    let box_widths: Vec<usize> = labels.iter().map(|label| text_width(label) + 4).collect();
+    let centers = column_centers(diagram, &box_widths);
+
+    // ノートの位置（左端, 幅）
+    let note_span = |placement: NotePlacement, text: &str| {
+        let width = text_width(text) + 4;
+        match placement {
+            NotePlacement::RightOf(p) => (centers[p] + 2, width),
+            NotePlacement::LeftOf(p) => (centers[p].saturating_sub(width + 1), width),
+            NotePlacement::Over(first, last) => {
+                let span = centers[last] - centers[first] + 5;
+                let width = width.max(span);
+                let middle = (centers[first] + centers[last]) / 2;
+                (middle.saturating_sub(width / 2), width)
+            }
+        }
+    };
+
+    // 全体の幅と高さ
+    let mut width = centers
+        .iter()
+        .zip(&box_widths)
+        .map(|(center, box_width)| center + box_width - box_width / 2)
+        .max()
+        .unwrap_or(0);
+    let mut height = 6;
+    for event in &diagram.events {
+        match event {
+            SequenceEvent::Message { from, to, text, .. } => {
+                if from == to {
+                    width = width.max(centers[*from] + 6 + text_width(text));
+                }
+                height += 2;
+            }
+            SequenceEvent::Note { placement, text } => {
+                let (x, note_width) = note_span(*placement, &truncate(text, MAX_TEXT_WIDTH));
+                width = width.max(x + note_width);
+                height += 3;
+            }
+            SequenceEvent::Block(_) | SequenceEvent::End => height += 1,
+        }
+    }
+
+    let mut canvas = Canvas::new(width, height);
+    let bottom = height - 3;
+    for (index, &center) in centers.iter().enumerate() {
+        let x = center - box_widths[index] / 2;
+        for y in [0, bottom] {
+            canvas.rect(x, y, box_widths[index], 3);
+            canvas.put_str(x + 2, y + 1, &labels[index]);
+        }
+        canvas.vline(center, 2, bottom);
+    }
+
+    let mut y = 3;
+    for event in &diagram.events {
+        match event {
+            SequenceEvent::Message {
+                from,
+                to,
+                text,
+                dashed,
+                head,
+            } => {
+                let (start, end) = (centers[*from], centers[*to]);
+                let text = truncate(text, MAX_TEXT_WIDTH);
+                if from == to {
+                    canvas.hline(start, start + 3, y);
+                    canvas.vline(start + 3, y, y + 1);
+                    canvas.hline(start + 1, start + 3, y + 1);
+                    canvas.put_str(start + 5, y, &text);
+                    if *head != ArrowHead::None {
+                        canvas.put_char(start + 1, y + 1, head_char(*head, false));
+                    }
+                } else {
+                    canvas.put_str(start.min(end) + 2, y, &text);
+                    if *dashed {
+                        for x in start.min(end) + 1..start.max(end) {
+                            if !centers.contains(&x) {
+                                canvas.put_char(x, y + 1, '╌');
+                            }
+                        }
+                    } else {
+                        canvas.hline(start, end, y + 1);
+                    }
+                    if *head != ArrowHead::None {
+                        let x = if end > start { end - 1 } else { end + 1 };
+                        canvas.put_char(x, y + 1, head_char(*head, end > start));
+                    }
+                }
+                y += 2;
+            }
+            SequenceEvent::Note { placement, text } => {
+                let text = truncate(text, MAX_TEXT_WIDTH);
+                let (x, note_width) = note_span(*placement, &text);
+                let inner = note_width - 2;
+                canvas.put_str(x, y, &format!("┌{}┐", "─".repeat(inner)));
+                canvas.put_str(
+                    x,
+                    y + 1,
+                    &format!("│ {}{} │", text, " ".repeat(inner - 2 - text_width(&text))),
+                );
+                canvas.put_str(x, y + 2, &format!("└{}┘", "─".repeat(inner)));
+                y += 3;
+            }
+            SequenceEvent::Block(label) => {
+                let label = format!("╌╌ {} ", truncate(label, MAX_TEXT_WIDTH));
+                let fill = width.saturating_sub(text_width(&label));
+                canvas.put_str(0, y, &format!("{}{}", label, "╌".repeat(fill)));
+                y += 1;
+            }
+            SequenceEvent::End => {
+                canvas.put_str(0, y, &"╌".repeat(width));
+                y += 1;
+            }
+        }
+    }
+
+    canvas.into_lines()
+}
+
+fn head_char(head: ArrowHead, rightward: bool) -> char {
+    match (head, rightward) {
+        (ArrowHead::Cross, _) => '×',
+        (_, true) => '▶',
+        (_, false) => '◀',
+    }
+}
+
+/// 参加者ボックスとメッセージのテキストが収まるように各列の中心位置を決める
+fn column_centers(diagram: &SequenceDiagram, box_widths: &[usize]) -> Vec<usize> {
+    let count = box_widths.len();
+    let mut gaps: Vec<usize> = (0..count)
+        .map(|i| {
+            if i == 0 {
+                box_widths[0] / 2
+            } else {
+                (box_widths[i - 1] + box_widths[i]) / 2 + 2
+            }
+        })
+        .collect();
+
+    for event in &diagram.events {
+        if let SequenceEvent::Message { from, to, text, .. } = event
+            && from != to
+        {
+            let (first, last) = ((*from).min(*to), (*from).max(*to));
+            let required = text_width(&truncate(text, MAX_TEXT_WIDTH)) + 4;
+            let current: usize = gaps[first + 1..=last].iter().sum();
+            if current < required {
+                gaps[last] += required - current;
+            }
+        }
+    }
+
+    gaps.iter()
+        .scan(0, |center, gap| {
+            *center += gap;
+            Some(*center)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "sequenceDiagram\n    participant U as User\n    actor S\n    U->>S: request\n    loop retry\n        S-->>U: reply\n    end\n    Note over U,S: done\n    S->>S: self\n    S-xU: fail";
+
+    #[test]
+    fn test_parse_sequence() {
+        let diagram = parse(SOURCE).unwrap();
+
+        let labels: Vec<&str> = diagram
+            .participants
+            .iter()
+            .map(|p| p.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["User", "S"]);
+        assert_eq!(diagram.events.len(), 7);
+        assert_eq!(
+            diagram.events[0],
+            SequenceEvent::Message {
+                from: 0,
+                to: 1,
+                text: "request".to_string(),
+                dashed: false,
+                head: ArrowHead::Arrow,
+            }
+        );
+        assert_eq!(
+            diagram.events[1],
+            SequenceEvent::Block("loop retry".to_string())
+        );
+        assert!(matches!(
+            diagram.events[2],
+            SequenceEvent::Message { dashed: true, .. }
+        ));
+        assert_eq!(
+            diagram.events[4],
+            SequenceEvent::Note {
+                placement: NotePlacement::Over(0, 1),
+                text: "done".to_string(),
+            }
+        );
+        assert!(matches!(
+            diagram.events[6],
+            SequenceEvent::Message {
+                head: ArrowHead::Cross,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_render_message() {
+        let diagram = parse("sequenceDiagram\n  A->>B: hi").unwrap();
+
+        assert_eq!(
+            render(&diagram),
+            vec![
+                "┌───┐  ┌───┐",
+                "│ A │  │ B │",
+                "└─┬─┘  └─┬─┘",
+                "  │ hi   │",
+                "  ├─────▶┤",
+                "┌─┴─┐  ┌─┴─┐",
+                "│ A │  │ B │",
+                "└───┘  └───┘",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_blocks_notes_and_self_messages() {
+        let lines = render(&parse(SOURCE).unwrap());
+
+        assert!(lines.iter().any(|line| line.starts_with("╌╌ loop retry ╌")));
+        assert!(lines.iter().any(|line| line.contains("◀╌")));
+        assert!(lines.iter().any(|line| line.contains("│ done")));
+        assert!(lines.iter().any(|line| line.contains("self")));
+        assert!(lines.iter().any(|line| line.contains('×')));
+    }
+}
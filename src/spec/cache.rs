@@ -0,0 +1,297 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::SystemTime,
+};
+
+use color_eyre::eyre::Result;
+
+use crate::git::GitInfo;
+use crate::spec::metadata::SpecMetadata;
+use crate::spec::parser::{Diagnostic, Task};
+use crate::spec::requirements::Requirement;
+
+/// ファイルの更新時刻とサイズ（変更の検出に使用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+/// ファイルの現在のスタンプ（存在しなければ `None`）
+fn stamp(path: &Path) -> Option<FileStamp> {
+    fs::metadata(path).ok().map(|metadata| FileStamp {
+        modified: metadata.modified().ok(),
+        len: metadata.len(),
+    })
+}
+
+/// 解析結果と、解析時点の依存ファイルのスタンプ
+#[derive(Debug)]
+struct Entry<T> {
+    stamps: Vec<Option<FileStamp>>,
+    value: T,
+    // 前回の `sweep` 以降に参照されたか
+    used: bool,
+}
+
+/// パスをキーにした解析結果のキャッシュ
+///
+/// 依存するファイルの更新時刻とサイズが前回と同じであれば、解析し直さずに前回の結果を返す。
+/// 複数のスレッドから同時に使えるよう、エントリーは `Mutex` で保護する。
+/// 探索で参照されなくなったエントリー（削除された Spec など）は `sweep` で破棄する。
+#[derive(Debug)]
+pub struct ParseCache<T> {
+    entries: Mutex<HashMap<PathBuf, Entry<T>>>,
+    // `parse` を呼んだ回数（キャッシュが使われなかった回数）
+    parses: AtomicUsize,
+}
+
+impl<T> Default for ParseCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            parses: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl<T> ParseCache<T> {
+    /// 前回の `sweep` 以降に参照されなかったエントリーを破棄する
+    pub fn sweep(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, entry| std::mem::take(&mut entry.used));
+        }
+    }
+
+    /// これまでに解析した回数
+    #[cfg(test)]
+    pub fn parse_count(&self) -> usize {
+        self.parses.load(Ordering::Relaxed)
+    }
+
+    /// キャッシュしているエントリー数
+    #[cfg(test)]
+    pub fn entry_count(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.len())
+    }
+}
+
+impl<T: Clone> ParseCache<T> {
+    /// キャッシュ済みの結果を返すか、`parse` で解析してキャッシュする
+    ///
+    /// 解析に失敗した場合はキャッシュせず、次回の探索で解析し直す。
+    ///
+    /// # 引数
+    /// * `key` - キャッシュのキー（ファイルやフォルダのパス）
+    /// * `dependencies` - 変更を検出する対象のファイル
+    /// * `parse` - 解析処理
+    ///
+    /// # 戻り値
+    /// * `Ok(T)` - キャッシュ済み、または解析した結果
+    pub fn get_or_parse(
+        &self,
+        key: &Path,
+        dependencies: &[PathBuf],
+        parse: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        let stamps: Vec<Option<FileStamp>> = dependencies.iter().map(|path| stamp(path)).collect();
+
+        // 解析中は他のスレッドを待たせないよう、ロックは参照と登録の間だけ取る
+        if let Ok(mut entries) = self.entries.lock()
+            && let Some(entry) = entries.get_mut(key)
+            && entry.stamps == stamps
+        {
+            entry.used = true;
+            return Ok(entry.value.clone());
+        }
+
+        self.parses.fetch_add(1, Ordering::Relaxed);
+        let value = parse()?;
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                key.to_path_buf(),
+                Entry {
+                    stamps,
+                    value: value.clone(),
+                    used: true,
+                },
+            );
+        }
+        Ok(value)
+    }
+}
+
+/// Spec 名ごとの git 履歴の要約
+type SpecHistory = HashMap<String, GitInfo>;
+
+/// `.kiro/specs` ごとの git 履歴のキャッシュ
+///
+/// 履歴はコミットでしか変わらないため、HEAD が前回と同じであれば `git log` を実行し直さない。
+#[derive(Debug, Default)]
+pub struct HistoryCache {
+    // `.kiro/specs` のパスごとの、読み取った時点の HEAD と Spec 名ごとの履歴
+    entries: Mutex<HashMap<PathBuf, (String, SpecHistory)>>,
+}
+
+impl HistoryCache {
+    /// HEAD が前回と同じならキャッシュ済みの履歴を返し、変わっていれば `read` で読み直す
+    ///
+    /// # 引数
+    /// * `specs_dir` - `.kiro/specs` ディレクトリ
+    /// * `head` - 現在の HEAD のハッシュ
+    /// * `read` - 履歴を読み取る処理
+    ///
+    /// # 戻り値
+    /// * `HashMap<String, GitInfo>` - Spec 名ごとの履歴の要約
+    pub fn get_or_read(
+        &self,
+        specs_dir: &Path,
+        head: &str,
+        read: impl FnOnce() -> SpecHistory,
+    ) -> SpecHistory {
+        if let Ok(entries) = self.entries.lock()
+            && let Some((cached_head, history)) = entries.get(specs_dir)
+            && cached_head == head
+        {
+            return history.clone();
+        }
+
+        let history = read();
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(specs_dir.to_path_buf(), (head.to_string(), history.clone()));
+        }
+        history
+    }
+}
+
+/// Spec の探索で使う解析結果のキャッシュ
+#[derive(Debug, Default)]
+pub struct ScanCache {
+    // tasks.md のタスクツリーと診断（見積もりは適用前）
+    pub tasks: ParseCache<(Vec<Task>, Vec<Diagnostic>)>,
+    // requirements.md の要件
+    pub requirements: ParseCache<Vec<Requirement>>,
+    // Spec フォルダのメタデータ（spec.toml / requirements.md / tasks.md）
+    pub metadata: ParseCache<SpecMetadata>,
+    // Spec フォルダごとの git 履歴（HEAD が変わったときだけ読み直す）
+    pub history: HistoryCache,
+}
+
+impl ScanCache {
+    /// 直前の探索で参照されなかったエントリーを破棄する（探索のたびに呼ぶ）
+    pub fn sweep(&self) {
+        self.tasks.sweep();
+        self.requirements.sweep();
+        self.metadata.sweep();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use color_eyre::eyre::eyre;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_get_or_parse_reuses_unchanged_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("tasks.md");
+        fs::write(&path, "one").unwrap();
+        let dependencies = vec![path.clone()];
+        let cache = ParseCache::default();
+        let parses = Cell::new(0);
+        let parse = || {
+            parses.set(parses.get() + 1);
+            Ok(fs::read_to_string(&path)?)
+        };
+
+        assert_eq!(
+            cache.get_or_parse(&path, &dependencies, parse).unwrap(),
+            "one"
+        );
+        assert_eq!(
+            cache.get_or_parse(&path, &dependencies, parse).unwrap(),
+            "one"
+        );
+        assert_eq!(parses.get(), 1);
+
+        // サイズが変われば解析し直す
+        fs::write(&path, "three").unwrap();
+        assert_eq!(
+            cache.get_or_parse(&path, &dependencies, parse).unwrap(),
+            "three"
+        );
+        assert_eq!(parses.get(), 2);
+
+        // ファイルが消えた場合もスタンプの変化として検出する
+        fs::remove_file(&path).unwrap();
+        assert!(cache.get_or_parse(&path, &dependencies, parse).is_err());
+        assert_eq!(parses.get(), 3);
+    }
+
+    #[test]
+    fn test_sweep_evicts_unused_entries() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = temp_dir.path().join("first.md");
+        let second = temp_dir.path().join("second.md");
+        let cache = ParseCache::default();
+
+        cache.get_or_parse(&first, &[], || Ok(1)).unwrap();
+        cache.get_or_parse(&second, &[], || Ok(2)).unwrap();
+        cache.sweep();
+        assert_eq!(cache.entry_count(), 2);
+
+        // 次の探索で参照されなかったエントリーだけを破棄する
+        cache.get_or_parse(&first, &[], || Ok(1)).unwrap();
+        cache.sweep();
+        assert_eq!(cache.entry_count(), 1);
+        assert_eq!(cache.get_or_parse(&first, &[], || Ok(3)).unwrap(), 1);
+        assert_eq!(cache.parse_count(), 2);
+    }
+
+    #[test]
+    fn test_history_cache_reads_again_when_head_moves() {
+        let specs_dir = Path::new("/project/.kiro/specs");
+        let cache = HistoryCache::default();
+        let reads = Cell::new(0);
+        let read = || {
+            reads.set(reads.get() + 1);
+            HashMap::new()
+        };
+
+        cache.get_or_read(specs_dir, "a1", read);
+        cache.get_or_read(specs_dir, "a1", read);
+        assert_eq!(reads.get(), 1);
+
+        // 新しいコミットで HEAD が変われば読み直す
+        cache.get_or_read(specs_dir, "b2", read);
+        assert_eq!(reads.get(), 2);
+    }
+
+    #[test]
+    fn test_get_or_parse_does_not_cache_errors() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("spec.toml");
+        let dependencies = vec![path.clone()];
+        let cache: ParseCache<u32> = ParseCache::default();
+
+        assert!(
+            cache
+                .get_or_parse(&path, &dependencies, || Err(eyre!("broken")))
+                .is_err()
+        );
+        assert_eq!(
+            cache.get_or_parse(&path, &dependencies, || Ok(1)).unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::spec::parser::{Task, TaskStatus, flatten_tasks};
+
+/// 比較元からのタスクの変化
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskChange {
+    /// 比較元になかったタスク
+    Added,
+    /// 比較元では未完了で、現在は完了
+    Completed,
+    /// 比較元では完了で、現在は未完了
+    Reopened,
+    /// 比較元では未着手または完了で、現在は実行中
+    Started,
+    /// 比較元では実行中で、現在は未着手
+    Paused,
+}
+
+impl TaskChange {
+    pub fn label(self) -> &'static str {
+        match self {
+            TaskChange::Added => "new",
+            TaskChange::Completed => "done",
+            TaskChange::Reopened => "reopened",
+            TaskChange::Started => "started",
+            TaskChange::Paused => "paused",
+        }
+    }
+}
+
+/// 比較元（git の ref）と作業ツリーの tasks.md の差分
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpecDelta {
+    // 作業ツリーのタスクの行番号ごとの変化
+    pub changes: HashMap<usize, TaskChange>,
+    // 比較元にあり、作業ツリーで削除されたタスクのタイトル
+    pub removed: Vec<String>,
+}
+
+impl SpecDelta {
+    /// 指定した変化のタスク数
+    pub fn count(&self, change: TaskChange) -> usize {
+        self.changes.values().filter(|c| **c == change).count()
+    }
+
+    /// 変化がないか
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.removed.is_empty()
+    }
+
+    /// リストビューに表示する要約（`+3 done, 2 new tasks, 1 removed` など）
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        let completed = self.count(TaskChange::Completed);
+        if completed > 0 {
+            parts.push(format!("+{} done", completed));
+        }
+        let reopened = self.count(TaskChange::Reopened);
+        if reopened > 0 {
+            parts.push(format!("{} reopened", reopened));
+        }
+        let added = self.count(TaskChange::Added);
+        if added > 0 {
+            parts.push(format!(
+                "{} new task{}",
+                added,
+                if added == 1 { "" } else { "s" }
+            ));
+        }
+        if !self.removed.is_empty() {
+            parts.push(format!("{} removed", self.removed.len()));
+        }
+        let started = self.count(TaskChange::Started);
+        if started > 0 {
+            parts.push(format!("{} started", started));
+        }
+        let paused = self.count(TaskChange::Paused);
+        if paused > 0 {
+            parts.push(format!("{} paused", paused));
+        }
+        parts.join(", ")
+    }
+}
+
+/// タスクを対応付けるキー（番号は振り直されることがあるためタイトルで対応付ける）
+fn task_key(task: &Task) -> String {
+    task.title.trim().to_lowercase()
+}
+
+/// 比較元と作業ツリーのタスクツリーを比較する
+///
+/// タスクはタイトルで対応付け、同じタイトルのタスクが複数ある場合は出現順に対応付ける。
+///
+/// # 引数
+/// * `base` - 比較元のタスクツリー
+/// * `current` - 作業ツリーのタスクツリー
+///
+/// # 戻り値
+/// * `SpecDelta` - 追加・状態の変化・削除されたタスク
+pub fn diff_tasks(base: &[Task], current: &[Task]) -> SpecDelta {
+    let mut remaining: HashMap<String, VecDeque<&Task>> = HashMap::new();
+    for task in flatten_tasks(base) {
+        remaining.entry(task_key(task)).or_default().push_back(task);
+    }
+
+    let mut delta = SpecDelta::default();
+    for task in flatten_tasks(current) {
+        let previous = remaining
+            .get_mut(&task_key(task))
+            .and_then(|tasks| tasks.pop_front());
+        let change = match previous.map(|previous| (previous.status, task.status)) {
+            None => Some(TaskChange::Added),
+            Some((before, after)) if before == after => None,
+            Some((_, TaskStatus::Done)) => Some(TaskChange::Completed),
+            Some((TaskStatus::Done, _)) => Some(TaskChange::Reopened),
+            Some((_, TaskStatus::InProgress)) => Some(TaskChange::Started),
+            // 残るのは実行中から未着手に戻った場合だけ
+            Some((_, TaskStatus::Todo)) => Some(TaskChange::Paused),
+        };
+        if let Some(change) = change {
+            delta.changes.insert(task.line, change);
+        }
+    }
+
+    // 対応付かなかった比較元のタスクを元の順に並べる
+    let mut removed: Vec<&Task> = remaining.into_values().flatten().collect();
+    removed.sort_by_key(|task| task.line);
+    delta.removed = removed.into_iter().map(|task| task.title.clone()).collect();
+    delta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parser::parse_tasks;
+
+    #[test]
+    fn test_diff_tasks() {
+        let base = parse_tasks(
+            "- [ ] 1. Design API\n- [ ] 2. Build API\n- [x] 3. Write docs\n- [ ] 4. Old task\n",
+        );
+        let current = parse_tasks(
+            "- [x] 1. Design API\n- [-] 2. Build API\n- [ ] 3. Write docs\n- [x] 4. Add tests\n- [ ] 5. Ship\n",
+        );
+
+        let delta = diff_tasks(&base, &current);
+
+        assert_eq!(delta.changes.get(&1), Some(&TaskChange::Completed));
+        assert_eq!(delta.changes.get(&2), Some(&TaskChange::Started));
+        assert_eq!(delta.changes.get(&3), Some(&TaskChange::Reopened));
+        assert_eq!(delta.changes.get(&4), Some(&TaskChange::Added));
+        assert_eq!(delta.changes.get(&5), Some(&TaskChange::Added));
+        assert_eq!(delta.removed, vec!["Old task".to_string()]);
+        assert_eq!(
+            delta.summary(),
+            "+1 done, 1 reopened, 2 new tasks, 1 removed, 1 started"
+        );
+    }
+
+    #[test]
+    fn test_diff_tasks_matches_renumbered_tasks() {
+        // 番号が振り直されてもタイトルで対応付ける
+        let base = parse_tasks("- [ ] 1. Build\n- [ ] 2. Ship\n");
+        let current = parse_tasks("- [ ] 1. Plan\n- [ ] 2. Build\n- [x] 3. Ship\n");
+
+        let delta = diff_tasks(&base, &current);
+
+        assert_eq!(delta.summary(), "+1 done, 1 new task");
+        assert_eq!(delta.changes.get(&1), Some(&TaskChange::Added));
+        assert_eq!(delta.changes.get(&3), Some(&TaskChange::Completed));
+    }
+
+    #[test]
+    fn test_diff_tasks_in_progress_back_to_todo() {
+        let base = parse_tasks("- [-] 1. Build\n- [x] 2. Test\n");
+        let current = parse_tasks("- [ ] 1. Build\n- [-] 2. Test\n");
+
+        let delta = diff_tasks(&base, &current);
+
+        // 実行中から未着手に戻ったタスクは started ではない
+        assert_eq!(delta.changes.get(&1), Some(&TaskChange::Paused));
+        assert_eq!(delta.changes.get(&2), Some(&TaskChange::Reopened));
+        assert_eq!(delta.summary(), "1 reopened, 1 paused");
+    }
+
+    #[test]
+    fn test_diff_tasks_pairs_duplicate_titles_in_order() {
+        // 同じタイトルのタスクは出現順に対応付ける
+        let base = parse_tasks("- [x] 1. Review\n- [ ] 2. Review\n");
+        let current = parse_tasks("- [x] 1. Review\n- [-] 2. Review\n- [ ] 3. Review\n");
+
+        let delta = diff_tasks(&base, &current);
+
+        assert_eq!(delta.changes.get(&1), None);
+        assert_eq!(delta.changes.get(&2), Some(&TaskChange::Started));
+        assert_eq!(delta.changes.get(&3), Some(&TaskChange::Added));
+    }
+
+    #[test]
+    fn test_diff_tasks_unchanged() {
+        let tasks = parse_tasks("- [x] 1. Build\n  - [ ] 1.1 Test\n");
+        let delta = diff_tasks(&tasks, &tasks);
+        assert!(delta.is_empty());
+        assert_eq!(delta.summary(), "");
+    }
+}
@@ -0,0 +1,255 @@
+use std::{fs, path::Path};
+
+use color_eyre::eyre::{Result, eyre};
+
+use crate::spec::parser::Task;
+
+/// タスクの見積もり注記の解釈方法
+///
+/// プロジェクトの `.kiro/kiro-radar.toml` の `[estimates]` テーブルで変更できる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct EstimateConfig {
+    // `estimate: 5` 形式で見積もりとして読むキー（小文字）
+    pub keys: Vec<String>,
+    // 1 日（`d`）あたりの時間
+    pub hours_per_day: f64,
+    // 1 ポイント（`pt`）あたりの時間
+    pub hours_per_point: f64,
+    // 単位のない値（`estimate: 5`）の単位
+    pub default_unit: String,
+}
+
+impl Default for EstimateConfig {
+    fn default() -> Self {
+        Self {
+            keys: vec!["estimate".to_string()],
+            hours_per_day: 8.0,
+            hours_per_point: 1.0,
+            default_unit: "pt".to_string(),
+        }
+    }
+}
+
+impl EstimateConfig {
+    /// 単位 1 あたりの時間（未知の単位は `None`）
+    fn unit_hours(&self, unit: &str) -> Option<f64> {
+        match unit.to_lowercase().as_str() {
+            "h" | "hr" | "hrs" | "hour" | "hours" => Some(1.0),
+            "d" | "day" | "days" => Some(self.hours_per_day),
+            "w" | "wk" | "week" | "weeks" => Some(self.hours_per_day * 5.0),
+            "pt" | "pts" | "point" | "points" | "sp" => Some(self.hours_per_point),
+            _ => None,
+        }
+    }
+}
+
+/// 設定ファイルの名前（`.kiro/` 直下）
+pub const CONFIG_FILE: &str = "kiro-radar.toml";
+
+/// 設定ファイルの `[estimates]` テーブルを解析する
+///
+/// # 引数
+/// * `content` - TOML テキスト
+///
+/// # 戻り値
+/// * `Ok(EstimateConfig)` - 指定のない項目は既定値
+/// * `Err` - TOML として解析できない場合、または値の型が正しくない場合
+pub fn parse_estimate_config(content: &str) -> Result<EstimateConfig> {
+    let table: toml::Table = content.parse()?;
+    let mut config = EstimateConfig::default();
+    let Some(estimates) = table.get("estimates") else {
+        return Ok(config);
+    };
+    let estimates = estimates
+        .as_table()
+        .ok_or_else(|| eyre!("[estimates] must be a table"))?;
+
+    if let Some(keys) = estimates.get("keys") {
+        config.keys = keys
+            .as_array()
+            .and_then(|keys| {
+                keys.iter()
+                    .map(|key| key.as_str().map(str::to_lowercase))
+                    .collect()
+            })
+            .ok_or_else(|| eyre!("estimates.keys must be an array of strings"))?;
+    }
+    for (name, slot) in [
+        ("hours_per_day", &mut config.hours_per_day),
+        ("hours_per_point", &mut config.hours_per_point),
+    ] {
+        if let Some(value) = estimates.get(name) {
+            *slot = value
+                .as_float()
+                .or_else(|| value.as_integer().map(|n| n as f64))
+                .ok_or_else(|| eyre!("estimates.{} must be a number", name))?;
+        }
+    }
+    if let Some(unit) = estimates.get("default_unit") {
+        let unit = unit
+            .as_str()
+            .filter(|unit| config.unit_hours(unit).is_some())
+            .ok_or_else(|| eyre!("estimates.default_unit must be h, d, w or pt"))?;
+        config.default_unit = unit.to_string();
+    }
+
+    Ok(config)
+}
+
+/// プロジェクトの見積もり設定を読み込む
+///
+/// # 引数
+/// * `project_root` - プロジェクトのルートディレクトリ
+///
+/// # 戻り値
+/// * `Ok(EstimateConfig)` - 設定ファイルがなければ既定値
+pub fn read_estimate_config(project_root: &Path) -> Result<EstimateConfig> {
+    let path = project_root.join(".kiro").join(CONFIG_FILE);
+    if !path.exists() {
+        return Ok(EstimateConfig::default());
+    }
+    parse_estimate_config(&fs::read_to_string(path)?)
+}
+
+/// 1 行から見積もりを時間換算で読み取る
+///
+/// `(2h)` / `[3pt]` のように括弧で囲まれた `数値+単位` と、
+/// `estimate: 5` のような設定したキーの値を認識する。
+///
+/// # 引数
+/// * `line` - タスクのタイトルまたは説明行
+/// * `config` - 見積もりの解釈方法
+///
+/// # 戻り値
+/// * `Some(f64)` - 見積もり（時間）
+/// * `None` - 見積もりが書かれていない場合
+pub fn parse_estimate(line: &str, config: &EstimateConfig) -> Option<f64> {
+    for (open, close) in [('(', ')'), ('[', ']')] {
+        let mut rest = line;
+        while let Some(start) = rest.find(open) {
+            rest = &rest[start + open.len_utf8()..];
+            let Some(end) = rest.find(close) else {
+                break;
+            };
+            if let Some((value, unit)) = split_quantity(&rest[..end])
+                && !unit.is_empty()
+                && let Some(hours) = config.unit_hours(unit)
+            {
+                return Some(value * hours);
+            }
+        }
+    }
+
+    let text = line
+        .trim()
+        .trim_start_matches(['-', '*', '+', ' '])
+        .trim_matches(['_', '*']);
+    let (key, value) = text.split_once(':')?;
+    let key = key.trim().trim_matches(['_', '*']).to_lowercase();
+    if !config.keys.contains(&key) {
+        return None;
+    }
+    let (value, unit) = split_quantity(value.trim_matches(['_', '*', ' ']))?;
+    let unit = if unit.is_empty() {
+        config.default_unit.as_str()
+    } else {
+        unit
+    };
+    Some(value * config.unit_hours(unit)?)
+}
+
+/// `2h` / `1.5 d` / `5` を数値と単位に分ける
+fn split_quantity(text: &str) -> Option<(f64, &str)> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let value: f64 = text[..split].parse().ok()?;
+    let unit = text[split..].trim();
+    (value >= 0.0 && unit.chars().all(|c| c.is_ascii_alphabetic())).then_some((value, unit))
+}
+
+/// タスクツリーの各タスクに見積もりを設定する
+///
+/// タイトルを優先し、なければ説明行の最初の見積もりを使う。
+pub fn apply_estimates(tasks: &mut [Task], config: &EstimateConfig) {
+    for task in tasks {
+        task.estimate = std::iter::once(task.title.as_str())
+            .chain(task.description.iter().map(String::as_str))
+            .find_map(|line| parse_estimate(line, config));
+        apply_estimates(&mut task.children, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::spec::parser::parse_tasks;
+
+    #[test]
+    fn test_parse_estimate_annotations() {
+        let config = EstimateConfig::default();
+
+        assert_eq!(parse_estimate("Set up database (2h)", &config), Some(2.0));
+        assert_eq!(parse_estimate("Build API [3pt]", &config), Some(3.0));
+        assert_eq!(
+            parse_estimate("Migrate (optional) (1.5d)", &config),
+            Some(12.0)
+        );
+        assert_eq!(parse_estimate("_Estimate: 5_", &config), Some(5.0));
+        assert_eq!(parse_estimate("estimate: 1w", &config), Some(40.0));
+        // 単位のない括弧や要件番号は見積もりとして扱わない
+        assert_eq!(parse_estimate("Step (2) [1.1]", &config), None);
+        assert_eq!(parse_estimate("_Requirements: 1.1_", &config), None);
+    }
+
+    #[test]
+    fn test_parse_estimate_config() {
+        let config = parse_estimate_config(
+            "[estimates]\nkeys = [\"Effort\"]\nhours_per_point = 4\ndefault_unit = \"h\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.keys, vec!["effort"]);
+        assert_eq!(config.hours_per_point, 4.0);
+        assert_eq!(config.hours_per_day, 8.0);
+        assert_eq!(parse_estimate("effort: 3", &config), Some(3.0));
+        assert_eq!(parse_estimate("estimate: 3", &config), None);
+        assert_eq!(parse_estimate("[2pt]", &config), Some(8.0));
+        assert!(parse_estimate_config("[estimates]\ndefault_unit = \"parsec\"\n").is_err());
+    }
+
+    #[test]
+    fn test_read_estimate_config_defaults_without_file() {
+        let temp_dir = TempDir::new().unwrap();
+        assert_eq!(
+            read_estimate_config(temp_dir.path()).unwrap(),
+            EstimateConfig::default()
+        );
+
+        fs::create_dir_all(temp_dir.path().join(".kiro")).unwrap();
+        fs::write(
+            temp_dir.path().join(".kiro").join(CONFIG_FILE),
+            "[estimates]\nhours_per_day = 6\n",
+        )
+        .unwrap();
+        assert_eq!(
+            read_estimate_config(temp_dir.path()).unwrap().hours_per_day,
+            6.0
+        );
+    }
+
+    #[test]
+    fn test_apply_estimates_reads_title_and_description() {
+        let mut tasks = parse_tasks("- [ ] 1. Parent (1d)\n  - [ ] 1.1 Child\n    - estimate: 3\n");
+
+        apply_estimates(&mut tasks, &EstimateConfig::default());
+
+        assert_eq!(tasks[0].estimate, Some(8.0));
+        assert_eq!(tasks[0].children[0].estimate, Some(3.0));
+    }
+}
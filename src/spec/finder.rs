@@ -1,16 +1,31 @@
 use std::{
+    collections::HashMap,
     fs,
+    num::NonZeroUsize,
     path::{Path, PathBuf},
+    thread,
 };
 
 use color_eyre::eyre::Result;
+use ignore::WalkBuilder;
 
-use crate::spec::parser::parse_tasks_file;
+use crate::git::{GitInfo, head_commit, read_spec_history};
+use crate::spec::cache::ScanCache;
+use crate::spec::compare::SpecDelta;
+use crate::spec::estimate::{CONFIG_FILE, apply_estimates, read_estimate_config};
+use crate::spec::metadata::{METADATA_KEYS, SpecMetadata, read_spec_metadata};
+use crate::spec::parser::{
+    Diagnostic, ProgressMode, Task, count_effort, count_in_progress_tasks, count_required_tasks,
+    count_tasks, current_task, parse_tasks_file,
+};
+use crate::spec::requirements::{Requirement, parse_requirements_file};
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SpecSet {
     // spec name
     pub name: String,
+    // package path relative to the scan root in monorepo mode (empty otherwise)
+    pub package: String,
     // requirements.md path (reserved for future file opening feature)
     #[allow(dead_code)]
     pub requirements: Option<PathBuf>,
@@ -20,74 +35,445 @@ pub struct SpecSet {
     // tasks.md path (reserved for future file opening feature)
     #[allow(dead_code)]
     pub tasks: Option<PathBuf>,
-    // total tasks
-    pub total_tasks: Option<usize>,
-    // completed tasks
-    pub completed_tasks: Option<usize>,
+    // other markdown files in the spec folder, sorted by file name
+    pub documents: Vec<PathBuf>,
+    // task tree parsed from tasks.md
+    pub task_tree: Vec<Task>,
+    // numbered requirements parsed from requirements.md
+    pub requirement_list: Vec<Requirement>,
+    // owner / priority / status / due from front matter or spec.toml
+    pub metadata: SpecMetadata,
+    // malformed checkbox lines in tasks.md
+    pub diagnostics: Vec<Diagnostic>,
+    // files that could not be read or parsed (`tasks.md: <error>`)
+    pub errors: Vec<String>,
+    // last commit, author and commit count of the spec folder (None outside a git repository)
+    pub git: Option<GitInfo>,
+    // task changes against the compared git ref (None unless comparison is on)
+    pub delta: Option<SpecDelta>,
+}
+
+/// Spec フォルダの標準ファイル
+const STANDARD_FILES: [&str; 3] = ["requirements.md", "design.md", "tasks.md"];
+
+/// Spec のライフサイクル上のフェーズ
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// requirements.md のみ
+    Requirements,
+    /// design.md まで作成済み
+    Design,
+    /// tasks.md でタスクを計画済み（未着手）
+    Planned,
+    /// 実装中（完了または実行中のタスクがある）
+    InProgress,
+    /// 必須タスクがすべて完了
+    Done,
+}
+
+impl Phase {
+    /// すべてのフェーズ（進行順）
+    pub const ALL: [Phase; 5] = [
+        Phase::Requirements,
+        Phase::Design,
+        Phase::Planned,
+        Phase::InProgress,
+        Phase::Done,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Phase::Requirements => "requirements",
+            Phase::Design => "design",
+            Phase::Planned => "planned",
+            Phase::InProgress => "in progress",
+            Phase::Done => "done",
+        }
+    }
+}
+
+impl SpecSet {
+    /// ファイルの有無とタスクの進捗からフェーズを判定する
+    pub fn phase(&self) -> Phase {
+        let (required, completed) = count_required_tasks(&self.task_tree, ProgressMode::All);
+        if required > 0 && completed == required {
+            Phase::Done
+        } else if self.completed_tasks(ProgressMode::All) > 0 || self.in_progress_tasks() > 0 {
+            Phase::InProgress
+        } else if self.tasks.is_some() {
+            Phase::Planned
+        } else if self.design.is_some() {
+            Phase::Design
+        } else {
+            Phase::Requirements
+        }
+    }
+
+    /// 計算方法に従って数えた総タスク数
+    pub fn total_tasks(&self, mode: ProgressMode) -> usize {
+        count_tasks(&self.task_tree, mode).0
+    }
+
+    /// 計算方法に従って数えた完了タスク数
+    pub fn completed_tasks(&self, mode: ProgressMode) -> usize {
+        count_tasks(&self.task_tree, mode).1
+    }
+
+    /// 実行中のタスク数
+    pub fn in_progress_tasks(&self) -> usize {
+        count_in_progress_tasks(&self.task_tree)
+    }
+
+    /// 現在実行中のタスク
+    pub fn current_task(&self) -> Option<&Task> {
+        current_task(&self.task_tree)
+    }
+
+    /// 任意タスクを除いた総タスク数
+    pub fn required_tasks(&self, mode: ProgressMode) -> usize {
+        count_required_tasks(&self.task_tree, mode).0
+    }
+
+    /// 任意タスクを除いた完了タスク数
+    pub fn completed_required_tasks(&self, mode: ProgressMode) -> usize {
+        count_required_tasks(&self.task_tree, mode).1
+    }
+
+    /// 見積もりから計算した (総工数, 完了工数)（時間、任意タスクを除く）
+    pub fn effort(&self, mode: ProgressMode) -> (f64, f64) {
+        count_effort(&self.task_tree, mode)
+    }
+
+    /// 絞り込み条件に一致するか
+    ///
+    /// 空白区切りの各語がすべて一致する場合に true を返す。`owner:alice` のような
+    /// `キー:値` はメタデータのフィールドを、それ以外の語は Spec 名、パッケージと
+    /// すべてのフィールドを対象に、大文字小文字を区別せず部分一致で比較する。
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        let contains = |value: &str, term: &str| value.to_lowercase().contains(term);
+        filter
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .all(|term| match term.split_once(':') {
+                Some((key, value)) if METADATA_KEYS.contains(&key) => self
+                    .metadata
+                    .field(key)
+                    .is_some_and(|field| contains(field, value)),
+                _ => {
+                    contains(&self.name, &term)
+                        || contains(&self.package, &term)
+                        || METADATA_KEYS
+                            .iter()
+                            .filter_map(|key| self.metadata.field(key))
+                            .any(|field| contains(field, &term))
+                }
+            })
+    }
+}
+
+/// `.kiro/` を持つプロジェクトのルートを親ディレクトリへ遡って探す
+///
+/// git が `.git` を探すのと同様に `start` から順に親をたどる。ファイルシステムの
+/// ルート、または `.git` を持つディレクトリ（リポジトリの境界）で探索を打ち切る。
+///
+/// # 引数
+/// * `start` - 探索を始めるディレクトリ
+///
+/// # 戻り値
+/// * `Some(PathBuf)` - `.kiro/` を含むディレクトリ
+/// * `None` - 見つからなかった場合
+pub fn find_project_root(start: &Path) -> Option<PathBuf> {
+    for dir in start.ancestors() {
+        if dir.join(".kiro").is_dir() {
+            return Some(dir.to_path_buf());
+        }
+        if dir.join(".git").exists() {
+            break;
+        }
+    }
+    None
 }
 
 /// .kiro/specs 配下の全てのSpecを探索
 ///
+/// Spec フォルダごとの読み込みと解析は複数のスレッドで並列に行う。
+/// 前回の探索から更新時刻とサイズが変わっていないファイルは `cache` の解析結果を再利用する。
+/// git リポジトリ内であれば、各 Spec フォルダの最後のコミットとコミット数も読み取る
+/// （HEAD が前回の探索から変わっていなければ `cache` の結果を再利用する）。
+///
 /// # 引数
 /// * `project_root` - プロジェクトのルートディレクトリ
+/// * `cache` - 解析結果のキャッシュ
 ///
 /// # 戻り値
 /// * `Ok(Vec<SpecSet>)` - 見つけたSpecのリスト
-pub fn find_all_specs(project_root: &Path) -> Result<Vec<SpecSet>> {
+pub fn find_all_specs(project_root: &Path, cache: &ScanCache) -> Result<Vec<SpecSet>> {
     let specs_dir = project_root.join(".kiro").join("specs");
 
     if !specs_dir.exists() {
         return Ok(Vec::new());
     }
 
-    let mut spec_sets = Vec::new();
+    // 設定ファイルの誤りは Spec ごとではなくプロジェクト単位で報告する（`find_config_errors`）
+    let estimate_config = read_estimate_config(project_root).unwrap_or_default();
 
+    let mut spec_dirs = Vec::new();
     for entry in fs::read_dir(&specs_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir()
-            && let Some(name) = path.file_name()
-        {
-            let name = name.to_string_lossy().to_string();
-
-            let requirements = path.join("requirements.md");
-            let design = path.join("design.md");
-            let tasks = path.join("tasks.md");
-            let (total_tasks, completed_tasks) = parse_tasks_file(&tasks)?;
-
-            spec_sets.push(SpecSet {
-                name,
-                requirements: if requirements.exists() {
-                    Some(requirements)
-                } else {
-                    None
-                },
-                tasks: if tasks.exists() { Some(tasks) } else { None },
-                design: if design.exists() { Some(design) } else { None },
-                total_tasks: Some(total_tasks),
-                completed_tasks: Some(completed_tasks),
-            });
+        let path = entry?.path();
+        if path.is_dir() {
+            spec_dirs.push(path);
         }
     }
 
+    let mut spec_sets = scan_in_parallel(&spec_dirs, |path| {
+        let mut spec = read_spec(path, cache);
+        apply_estimates(&mut spec.task_tree, &estimate_config);
+        spec
+    });
     spec_sets.sort_by(|a, b| a.name.cmp(&b.name));
 
+    // 履歴はコミットでしか変わらないため、HEAD が同じ間は前回の結果を使う
+    let mut history = match head_commit(&specs_dir) {
+        Some(head) => cache
+            .history
+            .get_or_read(&specs_dir, &head, || read_spec_history(&specs_dir)),
+        None => HashMap::new(),
+    };
+    for spec in &mut spec_sets {
+        spec.git = history.remove(&spec.name);
+    }
+
     Ok(spec_sets)
 }
 
+/// プロジェクト単位の設定ファイル（`.kiro/kiro-radar.toml`）の誤りを返す
+///
+/// 誤りがあっても探索は既定の設定で続けるため、Spec ごとではなく一度だけ報告する。
+///
+/// # 引数
+/// * `project_root` - プロジェクトのルートディレクトリ
+///
+/// # 戻り値
+/// * `Vec<String>` - `ファイル: 内容` の形のエラー（誤りがなければ空）
+pub fn find_config_errors(project_root: &Path) -> Vec<String> {
+    let mut errors = Vec::new();
+    or_record(
+        read_estimate_config(project_root),
+        &format!(".kiro/{CONFIG_FILE}"),
+        &mut errors,
+    );
+    errors
+}
+
+/// Spec フォルダを読み込んで SpecSet を組み立てる
+///
+/// 読み込めないファイルがあっても `errors` に記録して続ける。
+///
+/// # 引数
+/// * `path` - Spec フォルダのパス
+/// * `cache` - 解析結果のキャッシュ
+fn read_spec(path: &Path, cache: &ScanCache) -> SpecSet {
+    let mut errors = Vec::new();
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let requirements = path.join("requirements.md");
+    let design = path.join("design.md");
+    let tasks = path.join("tasks.md");
+    let sidecar = path.join("spec.toml");
+
+    let (task_tree, diagnostics) = or_record(
+        cache
+            .tasks
+            .get_or_parse(&tasks, std::slice::from_ref(&tasks), || {
+                parse_tasks_file(&tasks)
+            }),
+        "tasks.md",
+        &mut errors,
+    );
+    let requirement_list = or_record(
+        cache
+            .requirements
+            .get_or_parse(&requirements, std::slice::from_ref(&requirements), || {
+                parse_requirements_file(&requirements)
+            }),
+        "requirements.md",
+        &mut errors,
+    );
+    let metadata = or_record(
+        cache.metadata.get_or_parse(
+            path,
+            &[sidecar, requirements.clone(), tasks.clone()],
+            || read_spec_metadata(path),
+        ),
+        "spec.toml",
+        &mut errors,
+    );
+    let documents = or_record(
+        find_documents(path).map_err(Into::into),
+        "spec folder",
+        &mut errors,
+    );
+
+    SpecSet {
+        name,
+        package: String::new(),
+        requirements: if requirements.exists() {
+            Some(requirements)
+        } else {
+            None
+        },
+        tasks: if tasks.exists() { Some(tasks) } else { None },
+        design: if design.exists() { Some(design) } else { None },
+        documents,
+        task_tree,
+        requirement_list,
+        metadata,
+        diagnostics,
+        errors,
+        git: None,
+        delta: None,
+    }
+}
+
+/// Spec フォルダを CPU のコア数に応じたスレッドに分けて読み込む
+///
+/// # 引数
+/// * `spec_dirs` - Spec フォルダのパス
+/// * `read` - 1 つの Spec フォルダを読み込む処理
+///
+/// # 戻り値
+/// * `Vec<SpecSet>` - 読み込んだ Spec（`spec_dirs` と同じ順序）
+fn scan_in_parallel<F>(spec_dirs: &[PathBuf], read: F) -> Vec<SpecSet>
+where
+    F: Fn(&Path) -> SpecSet + Sync,
+{
+    let threads = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    let chunk_size = spec_dirs.len().div_ceil(threads).max(1);
+    let read = &read;
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = spec_dirs
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || chunk.iter().map(|path| read(path)).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    })
+}
+
+/// Spec フォルダ内の標準の 3 ファイル以外の Markdown をファイル名順に返す
+fn find_documents(spec_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut documents = Vec::new();
+    for entry in fs::read_dir(spec_dir)? {
+        let path = entry?.path();
+        let is_markdown = path.extension().is_some_and(|ext| ext == "md");
+        let is_standard = path
+            .file_name()
+            .is_some_and(|name| STANDARD_FILES.iter().any(|file| name == *file));
+        if path.is_file() && is_markdown && !is_standard {
+            documents.push(path);
+        }
+    }
+    documents.sort();
+    Ok(documents)
+}
+
+/// 読み込みに失敗した場合はエラーを `ファイル: 内容` の形で記録し、既定値を返す
+fn or_record<T: Default>(result: Result<T>, source: &str, errors: &mut Vec<String>) -> T {
+    result.unwrap_or_else(|error| {
+        errors.push(format!("{}: {}", source, error));
+        T::default()
+    })
+}
+
+/// ディレクトリ配下の `.kiro/specs` を持つ Kiro ワークスペースをすべて探す
+///
+/// `.gitignore` などの ignore ファイルで除外されたディレクトリと `.git` は探索しない。
+///
+/// # 引数
+/// * `root` - 探索を始めるディレクトリ
+///
+/// # 戻り値
+/// * `Vec<PathBuf>` - `.kiro/` を含むディレクトリ（パス順）
+fn find_workspaces(root: &Path) -> Vec<PathBuf> {
+    let walker = WalkBuilder::new(root)
+        .hidden(false)
+        .require_git(false)
+        .filter_entry(|entry| entry.file_name() != ".git")
+        .build();
+
+    let mut workspaces: Vec<PathBuf> = walker
+        .flatten()
+        .filter(|entry| entry.file_name() == ".kiro" && entry.path().join("specs").is_dir())
+        .filter_map(|entry| entry.path().parent().map(Path::to_path_buf))
+        .collect();
+    workspaces.sort();
+    workspaces
+}
+
+/// ディレクトリ配下のすべての Kiro ワークスペースから Spec を探索（モノレポモード）
+///
+/// 各 Spec の `package` にはワークスペースの `root` からの相対パスを設定する。
+/// 読み込めないワークスペースがあっても `errors` に記録して残りの探索を続ける。
+///
+/// # 引数
+/// * `root` - 探索を始めるディレクトリ
+/// * `cache` - 解析結果のキャッシュ
+/// * `errors` - 探索できなかったワークスペースのエラーの記録先
+///
+/// # 戻り値
+/// * `Vec<SpecSet>` - パッケージ順、パッケージ内は名前順に並べた Spec のリスト
+pub fn find_all_specs_recursive(
+    root: &Path,
+    cache: &ScanCache,
+    errors: &mut Vec<String>,
+) -> Vec<SpecSet> {
+    let mut spec_sets = Vec::new();
+
+    for workspace in find_workspaces(root) {
+        let package = match workspace.strip_prefix(root) {
+            Ok(relative) if !relative.as_os_str().is_empty() => relative.display().to_string(),
+            _ => ".".to_string(),
+        };
+        let source = match package.as_str() {
+            "." => ".kiro/specs".to_string(),
+            package => format!("{package}/.kiro/specs"),
+        };
+        for mut spec in or_record(find_all_specs(&workspace, cache), &source, errors) {
+            spec.package = package.clone();
+            spec_sets.push(spec);
+        }
+    }
+
+    spec_sets
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
 
     use tempfile::TempDir;
 
-    use crate::spec::finder::find_all_specs;
+    use crate::spec::cache::ScanCache;
+    use crate::spec::finder::{
+        Phase, find_all_specs, find_all_specs_recursive, find_config_errors, find_project_root,
+    };
+    use crate::spec::parser::ProgressMode;
 
     #[test]
     fn test_find_all_specs_empty() {
         let temp_dir = TempDir::new().unwrap();
-        let result = find_all_specs(temp_dir.path()).unwrap();
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
         assert_eq!(result.len(), 0);
     }
 
@@ -106,7 +492,7 @@ mod tests {
         fs::create_dir_all(&dashboard).unwrap();
         fs::write(dashboard.join("requirements.md"), "#requirements").unwrap();
 
-        let result = find_all_specs(temp_dir.path()).unwrap();
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
 
         assert_eq!(result.len(), 2);
 
@@ -114,4 +500,378 @@ mod tests {
         assert_eq!(result[0].name, "dashboard");
         assert_eq!(result[1].name, "user-auth");
     }
+
+    #[test]
+    fn test_find_all_specs_reads_metadata() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join(".kiro").join("specs").join("billing");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(
+            spec_dir.join("tasks.md"),
+            "---\nowner: alice\npriority: high\n---\n- [x] 1. Done\n- [ ] 2. Todo\n",
+        )
+        .unwrap();
+
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
+
+        // フロントマターはタスクとして数えない
+        assert_eq!(result[0].total_tasks(ProgressMode::All), 2);
+        assert_eq!(result[0].metadata.owner.as_deref(), Some("alice"));
+        assert_eq!(result[0].metadata.priority.as_deref(), Some("high"));
+    }
+
+    #[test]
+    fn test_find_all_specs_applies_estimate_config() {
+        let temp_dir = TempDir::new().unwrap();
+        let kiro_dir = temp_dir.path().join(".kiro");
+        let spec_dir = kiro_dir.join("specs").join("billing");
+        fs::create_dir_all(&spec_dir).unwrap();
+        fs::write(
+            kiro_dir.join("kiro-radar.toml"),
+            "[estimates]\nhours_per_point = 2\n",
+        )
+        .unwrap();
+        fs::write(
+            spec_dir.join("tasks.md"),
+            "- [x] 1. Done [3pt]\n- [ ] 2. Todo (2h)\n- [ ] 3. Unestimated\n",
+        )
+        .unwrap();
+
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
+
+        assert_eq!(result[0].effort(ProgressMode::All), (8.0, 6.0));
+    }
+
+    #[test]
+    fn test_find_project_root_walks_up() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".kiro").join("specs")).unwrap();
+        let nested = root.join("src").join("components");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(find_project_root(&nested), Some(root.to_path_buf()));
+        assert_eq!(find_project_root(root), Some(root.to_path_buf()));
+    }
+
+    #[test]
+    fn test_find_project_root_stops_at_git_boundary() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        fs::create_dir_all(root.join(".kiro")).unwrap();
+        // .kiro/ の下位にある別リポジトリからは外側の .kiro/ を見ない
+        let repo = root.join("vendor").join("lib");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        let nested = repo.join("src");
+        fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(find_project_root(&nested), None);
+    }
+
+    #[test]
+    fn test_find_all_specs_recursive_groups_by_package() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for (package, spec) in [
+            ("services/billing", "invoices"),
+            ("apps/web", "login"),
+            ("apps/web", "dashboard"),
+            ("node_modules/pkg", "vendored"),
+        ] {
+            let spec_dir = root.join(package).join(".kiro").join("specs").join(spec);
+            fs::create_dir_all(&spec_dir).unwrap();
+            fs::write(spec_dir.join("tasks.md"), "- [x] 1. Done\n").unwrap();
+        }
+        // .gitignore で除外したディレクトリは探索しない
+        fs::write(root.join(".gitignore"), "node_modules/\n").unwrap();
+
+        let mut errors = Vec::new();
+        let result = find_all_specs_recursive(root, &ScanCache::default(), &mut errors);
+        assert!(errors.is_empty());
+
+        let names: Vec<(&str, &str)> = result
+            .iter()
+            .map(|spec| (spec.package.as_str(), spec.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("apps/web", "dashboard"),
+                ("apps/web", "login"),
+                ("services/billing", "invoices"),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_all_specs_recursive_records_unreadable_workspace() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        for package in ["apps/broken", "apps/web"] {
+            let spec_dir = root.join(package).join(".kiro").join("specs").join("login");
+            fs::create_dir_all(&spec_dir).unwrap();
+            fs::write(spec_dir.join("tasks.md"), "- [x] 1. Done\n").unwrap();
+        }
+        let broken = root.join("apps/broken/.kiro/specs");
+        fs::set_permissions(&broken, fs::Permissions::from_mode(0o000)).unwrap();
+        // root など権限を無視できる環境では読み込みに失敗しない
+        let unreadable = fs::read_dir(&broken).is_err();
+
+        let mut errors = Vec::new();
+        let result = find_all_specs_recursive(root, &ScanCache::default(), &mut errors);
+        fs::set_permissions(&broken, fs::Permissions::from_mode(0o755)).unwrap();
+
+        // 1 つのワークスペースの失敗で探索全体が止まらない
+        assert!(
+            result
+                .iter()
+                .any(|spec| spec.package == "apps/web" && spec.name == "login")
+        );
+        if unreadable {
+            assert_eq!(errors.len(), 1);
+            assert!(errors[0].starts_with("apps/broken/.kiro/specs: "));
+            assert!(result.iter().all(|spec| spec.package != "apps/broken"));
+        }
+    }
+
+    #[test]
+    fn test_config_errors_reported_once_per_project() {
+        let temp_dir = TempDir::new().unwrap();
+        let kiro_dir = temp_dir.path().join(".kiro");
+        for name in ["api", "auth"] {
+            let spec_dir = kiro_dir.join("specs").join(name);
+            fs::create_dir_all(&spec_dir).unwrap();
+            fs::write(spec_dir.join("tasks.md"), "- [ ] 1. Task (2h)\n").unwrap();
+        }
+        fs::write(
+            kiro_dir.join("kiro-radar.toml"),
+            "[estimates]\nhours_per_day = ",
+        )
+        .unwrap();
+
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
+
+        // Spec には記録せず、既定の設定で見積もりを読む
+        assert!(result.iter().all(|spec| spec.errors.is_empty()));
+        assert_eq!(result[0].effort(ProgressMode::All), (2.0, 0.0));
+        let errors = find_config_errors(temp_dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with(".kiro/kiro-radar.toml: "));
+    }
+
+    #[test]
+    fn test_find_all_specs_records_errors_per_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let specs_dir = temp_dir.path().join(".kiro").join("specs");
+        let broken = specs_dir.join("broken");
+        let healthy = specs_dir.join("healthy");
+        fs::create_dir_all(&broken).unwrap();
+        fs::create_dir_all(&healthy).unwrap();
+        // UTF-8 として読めない tasks.md と解析できない spec.toml
+        fs::write(broken.join("tasks.md"), [0xff, 0xfe, b'-']).unwrap();
+        fs::write(broken.join("spec.toml"), "owner = ").unwrap();
+        fs::write(healthy.join("tasks.md"), "- [x] 1. Done\n").unwrap();
+
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
+
+        // 1 つの Spec の失敗で探索全体が止まらない
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "broken");
+        assert_eq!(result[0].errors.len(), 2);
+        assert!(result[0].errors[0].starts_with("tasks.md: "));
+        assert!(result[0].errors[1].starts_with("spec.toml: "));
+        assert!(result[1].errors.is_empty());
+        assert_eq!(result[1].total_tasks(ProgressMode::All), 1);
+    }
+
+    #[test]
+    fn test_spec_phase() {
+        let temp_dir = TempDir::new().unwrap();
+        let specs_dir = temp_dir.path().join(".kiro").join("specs");
+        let files: [(&str, &[(&str, &str)]); 6] = [
+            ("a-requirements", &[("requirements.md", "# R")]),
+            (
+                "b-design",
+                &[("requirements.md", "# R"), ("design.md", "# D")],
+            ),
+            (
+                "c-planned",
+                &[("design.md", "# D"), ("tasks.md", "- [ ] 1. A\n")],
+            ),
+            ("d-started", &[("tasks.md", "- [-] 1. A\n- [ ] 2. B\n")]),
+            (
+                "e-done",
+                &[("tasks.md", "- [x] 1. A\n- [ ]* 2. Optional\n")],
+            ),
+            ("f-empty-tasks", &[("tasks.md", "# Tasks\n")]),
+        ];
+        for (name, files) in files {
+            let dir = specs_dir.join(name);
+            fs::create_dir_all(&dir).unwrap();
+            for (file, content) in files {
+                fs::write(dir.join(file), content).unwrap();
+            }
+        }
+
+        let phases: Vec<Phase> = find_all_specs(temp_dir.path(), &ScanCache::default())
+            .unwrap()
+            .iter()
+            .map(|spec| spec.phase())
+            .collect();
+
+        assert_eq!(
+            phases,
+            vec![
+                Phase::Requirements,
+                Phase::Design,
+                Phase::Planned,
+                Phase::InProgress,
+                Phase::Done,
+                Phase::Planned,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_all_specs_collects_extra_documents() {
+        let temp_dir = TempDir::new().unwrap();
+        let spec_dir = temp_dir.path().join(".kiro").join("specs").join("api");
+        fs::create_dir_all(&spec_dir).unwrap();
+        for file in [
+            "tasks.md",
+            "research.md",
+            "api.md",
+            "notes.txt",
+            "design.md",
+        ] {
+            fs::write(spec_dir.join(file), "# Doc").unwrap();
+        }
+
+        let result = find_all_specs(temp_dir.path(), &ScanCache::default()).unwrap();
+
+        let documents: Vec<String> = result[0]
+            .documents
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(documents, vec!["api.md", "research.md"]);
+    }
+
+    #[test]
+    fn test_find_all_specs_reparses_only_changed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let tasks = temp_dir
+            .path()
+            .join(".kiro")
+            .join("specs")
+            .join("api")
+            .join("tasks.md");
+        fs::create_dir_all(tasks.parent().unwrap()).unwrap();
+        fs::write(&tasks, "- [ ] 1. Task\n").unwrap();
+        let cache = ScanCache::default();
+        let completed = |cache: &ScanCache| {
+            find_all_specs(temp_dir.path(), cache).unwrap()[0].completed_tasks(ProgressMode::All)
+        };
+        assert_eq!(completed(&cache), 0);
+
+        // 更新時刻とサイズが同じなら内容が変わっていても前回の解析結果を使う
+        let modified = fs::metadata(&tasks).unwrap().modified().unwrap();
+        fs::write(&tasks, "- [x] 1. Task\n").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&tasks)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        assert_eq!(completed(&cache), 0);
+        assert_eq!(completed(&ScanCache::default()), 1);
+
+        // サイズが変われば解析し直す
+        fs::write(&tasks, "- [x] 1. Task done\n").unwrap();
+        assert_eq!(completed(&cache), 1);
+    }
+
+    #[test]
+    fn test_find_all_specs_reparses_only_touched_spec() {
+        let temp_dir = TempDir::new().unwrap();
+        let specs_dir = temp_dir.path().join(".kiro").join("specs");
+        for name in ["alpha", "beta", "gamma"] {
+            let spec_dir = specs_dir.join(name);
+            fs::create_dir_all(&spec_dir).unwrap();
+            fs::write(spec_dir.join("tasks.md"), "- [ ] 1. Task\n").unwrap();
+            fs::write(spec_dir.join("requirements.md"), "# Requirements\n").unwrap();
+        }
+        let cache = ScanCache::default();
+        find_all_specs(temp_dir.path(), &cache).unwrap();
+        assert_eq!(cache.tasks.parse_count(), 3);
+        assert_eq!(cache.requirements.parse_count(), 3);
+        assert_eq!(cache.metadata.parse_count(), 3);
+
+        // 1 つの Spec の tasks.md だけを変更して再探索する
+        fs::write(
+            specs_dir.join("beta").join("tasks.md"),
+            "- [x] 1. Task done\n",
+        )
+        .unwrap();
+        let result = find_all_specs(temp_dir.path(), &cache).unwrap();
+
+        assert_eq!(result[1].completed_tasks(ProgressMode::All), 1);
+        assert_eq!(cache.tasks.parse_count(), 4);
+        assert_eq!(cache.requirements.parse_count(), 3);
+        // メタデータは tasks.md のフロントマターにも依存する
+        assert_eq!(cache.metadata.parse_count(), 4);
+    }
+
+    #[test]
+    #[ignore = "3000 個の Spec を作成するため時間がかかる"]
+    fn test_find_all_specs_large_tree() {
+        const SPEC_COUNT: usize = 3000;
+
+        let temp_dir = TempDir::new().unwrap();
+        let specs_dir = temp_dir.path().join(".kiro").join("specs");
+        for i in 0..SPEC_COUNT {
+            let spec_dir = specs_dir.join(format!("spec-{i:04}"));
+            fs::create_dir_all(&spec_dir).unwrap();
+            let tasks: String = (1..=20)
+                .map(|n| {
+                    let mark = if n <= i % 21 { "x" } else { " " };
+                    format!("- [{mark}] {n}. Task {n}\n  - Detail\n  - _Requirements: 1.1_\n")
+                })
+                .collect();
+            fs::write(spec_dir.join("tasks.md"), tasks).unwrap();
+            fs::write(
+                spec_dir.join("requirements.md"),
+                "# Requirements\n\n### Requirement 1: Item\n\n1. WHEN a THEN the system SHALL b\n",
+            )
+            .unwrap();
+        }
+        let cache = ScanCache::default();
+
+        let result = find_all_specs(temp_dir.path(), &cache).unwrap();
+
+        assert_eq!(result.len(), SPEC_COUNT);
+        assert_eq!(result[0].name, "spec-0000");
+        assert_eq!(result[20].completed_tasks(ProgressMode::All), 20);
+        assert_eq!(result[21].completed_tasks(ProgressMode::All), 0);
+        assert_eq!(result[SPEC_COUNT - 1].requirement_list.len(), 1);
+
+        // 1 つの Spec だけ変更して再探索する
+        fs::write(
+            specs_dir.join("spec-0000").join("tasks.md"),
+            "- [x] 1. Only\n",
+        )
+        .unwrap();
+        let rescanned = find_all_specs(temp_dir.path(), &cache).unwrap();
+
+        assert_eq!(rescanned.len(), SPEC_COUNT);
+        assert_eq!(cache.tasks.parse_count(), SPEC_COUNT + 1);
+        assert_eq!(rescanned[0].total_tasks(ProgressMode::All), 1);
+        assert_eq!(
+            rescanned[1].total_tasks(ProgressMode::All),
+            result[1].total_tasks(ProgressMode::All)
+        );
+    }
 }
@@ -0,0 +1,371 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+use serde_json::Value;
+
+/// フックファイルの拡張子
+const HOOK_EXTENSION: &str = ".kiro.hook";
+
+/// フックを実行するきっかけ（`when.type`）
+#[derive(Debug, Clone, PartialEq)]
+pub enum HookTrigger {
+    /// ファイルを保存したとき
+    FileEdited,
+    /// ファイルを作成したとき
+    FileCreated,
+    /// ファイルを削除したとき
+    FileDeleted,
+    /// ユーザーが手動で実行したとき
+    UserTriggered,
+    /// プロンプトを送信したとき
+    PromptSubmit,
+    /// エージェントの実行が終わったとき
+    AgentStop,
+    /// 未知の値（記載されたまま表示する）
+    Unknown(String),
+}
+
+impl HookTrigger {
+    fn parse(value: &str) -> Self {
+        match value {
+            "fileEdited" => HookTrigger::FileEdited,
+            "fileCreated" => HookTrigger::FileCreated,
+            "fileDeleted" => HookTrigger::FileDeleted,
+            "userTriggered" => HookTrigger::UserTriggered,
+            "promptSubmit" => HookTrigger::PromptSubmit,
+            "agentStop" => HookTrigger::AgentStop,
+            _ => HookTrigger::Unknown(value.to_string()),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            HookTrigger::FileEdited => "file saved",
+            HookTrigger::FileCreated => "file created",
+            HookTrigger::FileDeleted => "file deleted",
+            HookTrigger::UserTriggered => "manual",
+            HookTrigger::PromptSubmit => "prompt submit",
+            HookTrigger::AgentStop => "agent stop",
+            HookTrigger::Unknown(value) => value,
+        }
+    }
+
+    /// ファイルパターンが必要なきっかけかどうか
+    fn uses_patterns(&self) -> bool {
+        matches!(
+            self,
+            HookTrigger::FileEdited | HookTrigger::FileCreated | HookTrigger::FileDeleted
+        )
+    }
+}
+
+/// フックの状態（一覧での表示用）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HookStatus {
+    Enabled,
+    Disabled,
+    Invalid,
+}
+
+impl HookStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            HookStatus::Enabled => "enabled",
+            HookStatus::Disabled => "disabled",
+            HookStatus::Invalid => "invalid",
+        }
+    }
+}
+
+/// `.kiro/hooks` 配下のエージェントフック
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Hook {
+    // ファイル名（`lint-on-save.kiro.hook` など）
+    pub file_name: String,
+    // ファイルのパス
+    pub path: PathBuf,
+    // フック名（`name` がなければファイル名から拡張子を除いたもの）
+    pub name: String,
+    // 説明
+    pub description: Option<String>,
+    // 有効かどうか（`enabled` がなければ有効とみなす）
+    pub enabled: bool,
+    // 実行のきっかけ
+    pub trigger: Option<HookTrigger>,
+    // 対象のファイルパターン
+    pub patterns: Vec<String>,
+    // 実行する内容の種類（`askAgent` / `runCommand` など）
+    pub action: Option<String>,
+    // エージェントへの指示（`runCommand` の場合はコマンド）
+    pub prompt: Option<String>,
+    // 読み込み・検証で見つかった問題
+    pub problems: Vec<String>,
+}
+
+impl Hook {
+    pub fn status(&self) -> HookStatus {
+        if !self.problems.is_empty() {
+            HookStatus::Invalid
+        } else if self.enabled {
+            HookStatus::Enabled
+        } else {
+            HookStatus::Disabled
+        }
+    }
+}
+
+/// JSON の文字列フィールドを取り出す（型が違う場合は問題として記録）
+fn string_field(
+    object: &Value,
+    key: &str,
+    field: &str,
+    problems: &mut Vec<String>,
+) -> Option<String> {
+    match object.get(key) {
+        None | Some(Value::Null) => None,
+        Some(Value::String(value)) => Some(value.clone()),
+        Some(_) => {
+            problems.push(format!("`{field}` must be a string"));
+            None
+        }
+    }
+}
+
+/// フックファイルの内容を解析して検証する
+///
+/// # 引数
+/// * `file_name` - フックのファイル名（`name` がない場合の表示名に使用）
+/// * `content` - フックファイルの JSON テキスト
+///
+/// # 戻り値
+/// * `Hook` - 解析結果（不正な箇所は `problems` に記録）
+pub fn parse_hook(file_name: &str, content: &str) -> Hook {
+    let mut hook = Hook {
+        file_name: file_name.to_string(),
+        name: file_name
+            .strip_suffix(HOOK_EXTENSION)
+            .unwrap_or(file_name)
+            .to_string(),
+        enabled: true,
+        ..Default::default()
+    };
+
+    let root: Value = match serde_json::from_str(content) {
+        Ok(root) => root,
+        Err(err) => {
+            hook.problems.push(format!("invalid JSON: {err}"));
+            return hook;
+        }
+    };
+    if !root.is_object() {
+        hook.problems.push("hook must be a JSON object".to_string());
+        return hook;
+    }
+
+    let problems = &mut hook.problems;
+    if let Some(name) = string_field(&root, "name", "name", problems) {
+        hook.name = name;
+    }
+    hook.description = string_field(&root, "description", "description", problems);
+    match root.get("enabled") {
+        None => {}
+        Some(Value::Bool(enabled)) => hook.enabled = *enabled,
+        Some(_) => problems.push("`enabled` must be true or false".to_string()),
+    }
+
+    match root.get("when") {
+        Some(when) if when.is_object() => {
+            match string_field(when, "type", "when.type", problems) {
+                Some(kind) => hook.trigger = Some(HookTrigger::parse(&kind)),
+                None => problems.push("`when.type` is missing".to_string()),
+            }
+            match when.get("patterns") {
+                None => {}
+                Some(Value::Array(patterns)) => {
+                    for pattern in patterns {
+                        match pattern.as_str() {
+                            Some(pattern) => hook.patterns.push(pattern.to_string()),
+                            None => problems.push("`when.patterns` must be strings".to_string()),
+                        }
+                    }
+                }
+                Some(_) => problems.push("`when.patterns` must be an array".to_string()),
+            }
+        }
+        Some(_) => problems.push("`when` must be an object".to_string()),
+        None => problems.push("`when` is missing".to_string()),
+    }
+    if let Some(trigger) = &hook.trigger {
+        if let HookTrigger::Unknown(kind) = trigger {
+            problems.push(format!("unknown trigger `{kind}`"));
+        } else if trigger.uses_patterns() && hook.patterns.is_empty() {
+            problems.push("file trigger has no `when.patterns`".to_string());
+        }
+    }
+
+    match root.get("then") {
+        Some(then) if then.is_object() => {
+            hook.action = string_field(then, "type", "then.type", problems);
+            hook.prompt = string_field(then, "prompt", "then.prompt", problems)
+                .or_else(|| string_field(then, "command", "then.command", problems));
+            match hook.action.as_deref() {
+                None => problems.push("`then.type` is missing".to_string()),
+                Some("askAgent") if hook.prompt.is_none() => {
+                    problems.push("`then.prompt` is missing".to_string())
+                }
+                Some("runCommand") if hook.prompt.is_none() => {
+                    problems.push("`then.command` is missing".to_string())
+                }
+                _ => {}
+            }
+        }
+        Some(_) => problems.push("`then` must be an object".to_string()),
+        None => problems.push("`then` is missing".to_string()),
+    }
+
+    hook
+}
+
+/// `.kiro/hooks` 配下のフックを探索
+///
+/// # 引数
+/// * `project_root` - プロジェクトのルートディレクトリ
+///
+/// # 戻り値
+/// * `Ok(Vec<Hook>)` - ファイル名順のフック（ディレクトリがなければ空）
+pub fn find_hooks(project_root: &Path) -> Result<Vec<Hook>> {
+    let hooks_dir = project_root.join(".kiro").join("hooks");
+    if !hooks_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut hooks = Vec::new();
+    for entry in fs::read_dir(&hooks_dir)? {
+        let path = entry?.path();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !path.is_file() || !file_name.ends_with(HOOK_EXTENSION) {
+            continue;
+        }
+
+        // 読めないファイルも一覧には表示し、不正なフックとして扱う
+        let mut hook = match fs::read_to_string(&path) {
+            Ok(content) => parse_hook(&file_name, &content),
+            Err(err) => {
+                let mut hook = parse_hook(&file_name, "{}");
+                hook.problems = vec![format!("failed to read: {err}")];
+                hook
+            }
+        };
+        hook.path = path;
+        hooks.push(hook);
+    }
+
+    hooks.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+    Ok(hooks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_hook() {
+        let hook = parse_hook(
+            "lint.kiro.hook",
+            r#"{
+                "enabled": false,
+                "name": "Lint on save",
+                "description": "Run clippy",
+                "version": "1",
+                "when": { "type": "fileEdited", "patterns": ["src/**/*.rs"] },
+                "then": { "type": "askAgent", "prompt": "Run cargo clippy" }
+            }"#,
+        );
+
+        assert_eq!(hook.name, "Lint on save");
+        assert_eq!(hook.description.as_deref(), Some("Run clippy"));
+        assert_eq!(hook.trigger, Some(HookTrigger::FileEdited));
+        assert_eq!(hook.patterns, vec!["src/**/*.rs"]);
+        assert_eq!(hook.action.as_deref(), Some("askAgent"));
+        assert_eq!(hook.prompt.as_deref(), Some("Run cargo clippy"));
+        assert!(hook.problems.is_empty());
+        assert_eq!(hook.status(), HookStatus::Disabled);
+    }
+
+    #[test]
+    fn test_parse_hook_invalid() {
+        // JSON として読めない
+        let hook = parse_hook("broken.kiro.hook", "{ \"name\": ");
+        assert_eq!(hook.name, "broken");
+        assert_eq!(hook.status(), HookStatus::Invalid);
+        assert!(hook.problems[0].starts_with("invalid JSON"));
+
+        // ファイルのきっかけなのにパターンがなく、プロンプトもない
+        let hook = parse_hook(
+            "save.kiro.hook",
+            r#"{ "when": { "type": "fileCreated" }, "then": { "type": "askAgent" } }"#,
+        );
+        assert_eq!(
+            hook.problems,
+            vec![
+                "file trigger has no `when.patterns`".to_string(),
+                "`then.prompt` is missing".to_string()
+            ]
+        );
+
+        // 未知のきっかけと型の誤り
+        let hook = parse_hook(
+            "odd.kiro.hook",
+            r#"{ "enabled": "yes", "when": { "type": "onBuild" }, "then": { "type": "askAgent", "prompt": "x" } }"#,
+        );
+        assert_eq!(
+            hook.trigger,
+            Some(HookTrigger::Unknown("onBuild".to_string()))
+        );
+        assert_eq!(
+            hook.problems,
+            vec![
+                "`enabled` must be true or false".to_string(),
+                "unknown trigger `onBuild`".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_hooks() {
+        let temp_dir = TempDir::new().unwrap();
+        let hooks_dir = temp_dir.path().join(".kiro").join("hooks");
+        fs::create_dir_all(&hooks_dir).unwrap();
+        fs::write(
+            hooks_dir.join("manual.kiro.hook"),
+            r#"{ "name": "Review", "when": { "type": "userTriggered" }, "then": { "type": "askAgent", "prompt": "Review" } }"#,
+        )
+        .unwrap();
+        fs::write(hooks_dir.join("broken.kiro.hook"), "not json").unwrap();
+        fs::write(hooks_dir.join("notes.json"), "{}").unwrap();
+
+        let hooks = find_hooks(temp_dir.path()).unwrap();
+
+        let names: Vec<&str> = hooks.iter().map(|hook| hook.file_name.as_str()).collect();
+        assert_eq!(names, vec!["broken.kiro.hook", "manual.kiro.hook"]);
+        assert_eq!(hooks[0].status(), HookStatus::Invalid);
+        assert_eq!(hooks[1].status(), HookStatus::Enabled);
+        assert_eq!(hooks[1].trigger, Some(HookTrigger::UserTriggered));
+    }
+
+    #[test]
+    fn test_find_hooks_without_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(find_hooks(temp_dir.path()).unwrap().is_empty());
+    }
+}
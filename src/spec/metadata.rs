@@ -0,0 +1,274 @@
+use std::{fs, path::Path};
+
+use color_eyre::eyre::Result;
+
+/// Spec に付与するチームのメタデータ
+///
+/// requirements.md / tasks.md 先頭の YAML フロントマター、または Spec フォルダの
+/// `spec.toml` から読み込む。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpecMetadata {
+    // 担当者
+    pub owner: Option<String>,
+    // 優先度（`high` / `P1` / `1` など）
+    pub priority: Option<String>,
+    // チームで管理するステータス（`draft` / `review` など）
+    pub status: Option<String>,
+    // 期日（`YYYY-MM-DD`）
+    pub due: Option<String>,
+}
+
+/// メタデータとして扱うキー
+pub const METADATA_KEYS: [&str; 4] = ["owner", "priority", "status", "due"];
+
+impl SpecMetadata {
+    /// キー名でフィールドを取得する
+    pub fn field(&self, key: &str) -> Option<&str> {
+        match key {
+            "owner" => self.owner.as_deref(),
+            "priority" => self.priority.as_deref(),
+            "status" => self.status.as_deref(),
+            "due" => self.due.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn set_field(&mut self, key: &str, value: String) {
+        let slot = match key {
+            "owner" => &mut self.owner,
+            "priority" => &mut self.priority,
+            "status" => &mut self.status,
+            "due" => &mut self.due,
+            _ => return,
+        };
+        if !value.is_empty() {
+            *slot = Some(value);
+        }
+    }
+
+    /// 未設定のフィールドを `other` の値で補う
+    fn or(self, other: SpecMetadata) -> SpecMetadata {
+        SpecMetadata {
+            owner: self.owner.or(other.owner),
+            priority: self.priority.or(other.priority),
+            status: self.status.or(other.status),
+            due: self.due.or(other.due),
+        }
+    }
+
+    /// 並べ替え用の優先度の順位（小さいほど優先度が高い、未設定は最後）
+    pub fn priority_rank(&self) -> usize {
+        let Some(priority) = self.priority.as_deref() else {
+            return usize::MAX;
+        };
+        let priority = priority.trim().to_lowercase();
+        match priority.as_str() {
+            "critical" | "urgent" | "highest" => 0,
+            "high" => 1,
+            "medium" | "normal" => 2,
+            "low" => 3,
+            "lowest" => 4,
+            _ => priority
+                .trim_start_matches('p')
+                .parse::<usize>()
+                .map_or(usize::MAX - 1, |rank| rank),
+        }
+    }
+}
+
+/// Markdown 先頭の YAML フロントマター（`---` で囲まれたブロック）を解析する
+///
+/// `key: value` 形式の単純なスカラー値のみを読み取り、それ以外の行は無視する。
+///
+/// # 引数
+/// * `content` - Markdown テキスト
+///
+/// # 戻り値
+/// * `SpecMetadata` - 読み取ったメタデータ（フロントマターがなければ空）
+pub fn parse_front_matter(content: &str) -> SpecMetadata {
+    let mut metadata = SpecMetadata::default();
+    for (key, value) in front_matter_fields(content) {
+        metadata.set_field(&key.to_lowercase(), value);
+    }
+    metadata
+}
+
+/// フロントマターのトップレベルの `key: value` を順に取り出す
+///
+/// キーの大文字小文字はそのまま残し、値は引用符とコメントを取り除く。
+///
+/// # 引数
+/// * `content` - Markdown テキスト
+///
+/// # 戻り値
+/// * `Vec<(String, String)>` - キーと値の組（フロントマターがなければ空）
+pub fn front_matter_fields(content: &str) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let mut lines = content.lines();
+    if lines.next().map(str::trim_end) != Some("---") {
+        return fields;
+    }
+
+    for line in lines {
+        let line = line.trim_end();
+        if line == "---" || line == "..." {
+            break;
+        }
+        if line.starts_with([' ', '\t', '#']) {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once(':') {
+            fields.push((key.trim().to_string(), yaml_scalar(value)));
+        }
+    }
+
+    fields
+}
+
+/// YAML のスカラー値から引用符とコメントを取り除く
+fn yaml_scalar(value: &str) -> String {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(quote)
+            && let Some(end) = inner.find(quote)
+        {
+            return inner[..end].to_string();
+        }
+    }
+    match value.find(" #") {
+        Some(comment) => value[..comment].trim_end().to_string(),
+        None => value.to_string(),
+    }
+}
+
+/// `spec.toml` のトップレベルのキーを解析する
+///
+/// # 引数
+/// * `content` - TOML テキスト
+///
+/// # 戻り値
+/// * `Ok(SpecMetadata)` - 読み取ったメタデータ
+/// * `Err` - TOML として解析できない場合
+pub fn parse_spec_toml(content: &str) -> Result<SpecMetadata> {
+    let table: toml::Table = content.parse()?;
+    let mut metadata = SpecMetadata::default();
+
+    for key in METADATA_KEYS {
+        if let Some(value) = table.get(key) {
+            let value = match value {
+                toml::Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            metadata.set_field(key, value);
+        }
+    }
+
+    Ok(metadata)
+}
+
+/// Spec フォルダからメタデータを読み込む
+///
+/// 同じキーが複数の場所にある場合は `spec.toml`、requirements.md、tasks.md の順に優先する。
+///
+/// # 引数
+/// * `spec_dir` - Spec フォルダのパス
+///
+/// # 戻り値
+/// * `Err` - `spec.toml` を読み込めない、または解析できない場合
+pub fn read_spec_metadata(spec_dir: &Path) -> Result<SpecMetadata> {
+    let sidecar = spec_dir.join("spec.toml");
+    let mut metadata = if sidecar.exists() {
+        parse_spec_toml(&fs::read_to_string(sidecar)?)?
+    } else {
+        SpecMetadata::default()
+    };
+
+    // Markdown の読み込みエラーは各ファイルのパーサーが報告するため、ここでは無視する
+    for file in ["requirements.md", "tasks.md"] {
+        if let Ok(content) = fs::read_to_string(spec_dir.join(file)) {
+            metadata = metadata.or(parse_front_matter(&content));
+        }
+    }
+
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_front_matter() {
+        let content = "---\nowner: alice\npriority: \"high\"\nstatus: review # 確認待ち\ntags:\n  - ui\ndue: 2025-03-31\n---\n# Requirements\nowner: not metadata\n";
+
+        let metadata = parse_front_matter(content);
+
+        assert_eq!(metadata.owner.as_deref(), Some("alice"));
+        assert_eq!(metadata.priority.as_deref(), Some("high"));
+        assert_eq!(metadata.status.as_deref(), Some("review"));
+        assert_eq!(metadata.due.as_deref(), Some("2025-03-31"));
+    }
+
+    #[test]
+    fn test_parse_front_matter_requires_leading_block() {
+        assert_eq!(
+            parse_front_matter("# Title\n---\nowner: alice\n---\n"),
+            SpecMetadata::default()
+        );
+    }
+
+    #[test]
+    fn test_parse_spec_toml() {
+        let metadata =
+            parse_spec_toml("owner = \"bob\"\npriority = 2\ndue = 2025-04-01\n").unwrap();
+
+        assert_eq!(metadata.owner.as_deref(), Some("bob"));
+        assert_eq!(metadata.priority.as_deref(), Some("2"));
+        assert_eq!(metadata.due.as_deref(), Some("2025-04-01"));
+        assert!(parse_spec_toml("owner = ").is_err());
+    }
+
+    #[test]
+    fn test_read_spec_metadata_precedence() {
+        let temp_dir = TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        fs::write(dir.join("spec.toml"), "owner = \"carol\"\n").unwrap();
+        fs::write(
+            dir.join("requirements.md"),
+            "---\nowner: alice\nstatus: draft\n---\n# Requirements\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("tasks.md"),
+            "---\nstatus: done\npriority: low\n---\n",
+        )
+        .unwrap();
+
+        let metadata = read_spec_metadata(dir).unwrap();
+
+        assert_eq!(metadata.owner.as_deref(), Some("carol"));
+        assert_eq!(metadata.status.as_deref(), Some("draft"));
+        assert_eq!(metadata.priority.as_deref(), Some("low"));
+        assert_eq!(metadata.due, None);
+    }
+
+    #[test]
+    fn test_priority_rank() {
+        let rank = |priority: Option<&str>| {
+            SpecMetadata {
+                priority: priority.map(str::to_string),
+                ..Default::default()
+            }
+            .priority_rank()
+        };
+
+        assert!(rank(Some("critical")) < rank(Some("High")));
+        assert!(rank(Some("high")) < rank(Some("low")));
+        assert_eq!(rank(Some("P2")), 2);
+        assert!(rank(Some("someday")) < rank(None));
+    }
+}
@@ -1,3 +1,12 @@
+pub mod cache;
+pub mod compare;
+pub mod estimate;
 pub mod finder;
+pub mod hooks;
+pub mod metadata;
+pub mod outline;
 pub mod parser;
 pub mod reader;
+pub mod requirements;
+pub mod steering;
+pub mod traceability;
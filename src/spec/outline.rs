@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+/// Markdown ドキュメント内の見出し
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heading {
+    // 見出しレベル（`#` は 1）
+    pub level: usize,
+    // 見出しのテキスト
+    pub title: String,
+    // 行番号（1 始まり）
+    pub line: usize,
+    // 見出し行から次の同レベル以上の見出しの直前までの行数
+    pub section_lines: usize,
+}
+
+/// Markdown テキストから見出しの階層を抽出する
+///
+/// コードブロック内の `#` は見出しとして扱わない。
+///
+/// # 引数
+/// * `content` - Markdown テキスト
+///
+/// # 戻り値
+/// * `Vec<Heading>` - 出現順に並んだ見出しの一覧
+pub fn parse_outline(content: &str) -> Vec<Heading> {
+    let line_of = |offset: usize| content[..offset].matches('\n').count() + 1;
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut current: Option<(usize, usize, String)> = None;
+
+    for (event, range) in
+        Parser::new_ext(content, Options::ENABLE_YAML_STYLE_METADATA_BLOCKS).into_offset_iter()
+    {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                current = Some((level as usize, line_of(range.start), String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, title)) = current.as_mut() {
+                    title.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, line, title)) = current.take() {
+                    headings.push(Heading {
+                        level,
+                        title: title.trim().to_string(),
+                        line,
+                        section_lines: 0,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let total_lines = content.lines().count();
+    for index in 0..headings.len() {
+        let end = headings[index + 1..]
+            .iter()
+            .find(|next| next.level <= headings[index].level)
+            .map_or(total_lines + 1, |next| next.line);
+        headings[index].section_lines = end - headings[index].line;
+    }
+
+    headings
+}
+
+/// 見出しが子見出しを持つか（折りたたみ可能か）
+pub fn has_children(headings: &[Heading], index: usize) -> bool {
+    headings
+        .get(index + 1)
+        .is_some_and(|next| next.level > headings[index].level)
+}
+
+/// 折りたたまれた見出しの子孫を除いた見出しのインデックスを返す
+///
+/// # 引数
+/// * `headings` - 見出しの一覧
+/// * `collapsed` - 折りたたまれている見出しの行番号
+pub fn visible_headings(headings: &[Heading], collapsed: &HashSet<usize>) -> Vec<usize> {
+    let mut visible = Vec::new();
+    // 折りたたまれている祖先のレベル
+    let mut hidden_below: Option<usize> = None;
+
+    for (index, heading) in headings.iter().enumerate() {
+        if let Some(level) = hidden_below {
+            if heading.level > level {
+                continue;
+            }
+            hidden_below = None;
+        }
+
+        visible.push(index);
+        if collapsed.contains(&heading.line) {
+            hidden_below = Some(heading.level);
+        }
+    }
+
+    visible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &str = "# Design\n\nIntro\n\n## Overview\n\ntext\n\n```sh\n# not a heading\n```\n\n## Architecture\n\n### Modules\n\nmore\n\n### Data Flow\n\n## Testing `cargo test`\n";
+
+    #[test]
+    fn test_parse_outline() {
+        let headings = parse_outline(DOCUMENT);
+
+        let titles: Vec<&str> = headings.iter().map(|h| h.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Design",
+                "Overview",
+                "Architecture",
+                "Modules",
+                "Data Flow",
+                "Testing cargo test"
+            ]
+        );
+        assert_eq!(headings[1].level, 2);
+        assert_eq!(headings[1].line, 5);
+        // Overview: 5〜12 行目
+        assert_eq!(headings[1].section_lines, 8);
+        // Design はドキュメント全体
+        assert_eq!(headings[0].section_lines, 21);
+        // Architecture は子見出しを含む
+        assert_eq!(headings[2].section_lines, 8);
+    }
+
+    #[test]
+    fn test_visible_headings_with_collapsed_section() {
+        let headings = parse_outline(DOCUMENT);
+
+        assert_eq!(visible_headings(&headings, &HashSet::new()).len(), 6);
+        assert!(has_children(&headings, 2));
+        assert!(!has_children(&headings, 1));
+
+        // Architecture（13 行目）を折りたたむと子見出しが隠れる
+        let collapsed = HashSet::from([13]);
+        assert_eq!(visible_headings(&headings, &collapsed), vec![0, 1, 2, 5]);
+    }
+
+    #[test]
+    fn test_parse_outline_skips_front_matter() {
+        let headings = parse_outline("---\nowner: alice\n---\n# Requirements\n");
+
+        assert_eq!(headings.len(), 1);
+        assert_eq!(headings[0].title, "Requirements");
+        assert_eq!(headings[0].line, 4);
+    }
+
+    #[test]
+    fn test_parse_outline_empty() {
+        assert!(parse_outline("plain text\nno headings").is_empty());
+    }
+}
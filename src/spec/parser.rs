@@ -1,32 +1,799 @@
-use std::{fs, path::Path};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    ops::RangeInclusive,
+    path::Path,
+};
 
 use color_eyre::eyre::{Ok, Result};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
 
-pub fn parse_tasks_file(path: &Path) -> Result<(usize, usize)> {
+/// tasks.md 内の単一タスク（チェックボックス付きリスト項目）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Task {
+    // Kiro の階層番号（`1.` / `1.1` など）
+    pub id: Option<TaskId>,
+    // タスクのタイトル（チェックボックスと番号を除いたテキスト）
+    pub title: String,
+    // チェックボックスの状態
+    pub status: TaskStatus,
+    // Kiro の任意タスク（`- [ ]*`）
+    pub optional: bool,
+    // ネストの深さ（トップレベルは 0）
+    pub depth: usize,
+    // ファイル内の行番号（1 始まり）
+    pub line: usize,
+    // タスク直下にインデントされた説明行
+    pub description: Vec<String>,
+    // 見積もり（時間換算、`(2h)` / `[3pt]` / `estimate: 5` から読み取る）
+    pub estimate: Option<f64>,
+    // サブタスク
+    pub children: Vec<Task>,
+}
+
+/// チェックボックスの状態
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// `[ ]`
+    #[default]
+    Todo,
+    /// `[-]`（Kiro のエージェントが実行中）
+    InProgress,
+    /// `[x]` / `[X]`
+    Done,
+}
+
+impl Task {
+    /// 完了しているか
+    pub fn is_completed(&self) -> bool {
+        self.status == TaskStatus::Done
+    }
+
+    /// 実行中か
+    pub fn is_in_progress(&self) -> bool {
+        self.status == TaskStatus::InProgress
+    }
+
+    /// `_Requirements: 1.1, 2.3_` 形式で参照している要件番号を返す
+    pub fn requirement_refs(&self) -> Vec<String> {
+        std::iter::once(self.title.as_str())
+            .chain(self.description.iter().map(String::as_str))
+            .filter_map(requirement_ref_list)
+            .flat_map(|list| {
+                list.split([',', '、'])
+                    .map(|part| part.trim().trim_end_matches('.'))
+                    .filter(|part| is_dotted_number(part))
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Kiro のドット区切りタスク番号（例: `2.3` → `[2, 3]`）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TaskId(pub Vec<u32>);
+
+impl TaskId {
+    /// 番号が示す階層の深さ（`1` は 0、`1.1` は 1）
+    pub fn depth(&self) -> usize {
+        self.0.len().saturating_sub(1)
+    }
+
+    /// 親タスクの番号（トップレベルは `None`）
+    pub fn parent(&self) -> Option<TaskId> {
+        if self.0.len() > 1 {
+            Some(TaskId(self.0[..self.0.len() - 1].to_vec()))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.0.iter().map(|n| n.to_string()).collect();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+/// タスク番号に関する問題
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskIdIssue {
+    /// 同じ番号が複数回使われている
+    Duplicate {
+        id: TaskId,
+        line: usize,
+        first_line: usize,
+    },
+    /// 兄弟タスクの番号が飛んでいる
+    Gap {
+        expected: TaskId,
+        found: TaskId,
+        line: usize,
+    },
+    /// 番号の階層とインデントの深さが一致しない
+    DepthMismatch {
+        id: TaskId,
+        depth: usize,
+        line: usize,
+    },
+}
+
+impl TaskIdIssue {
+    /// 問題が見つかった行番号
+    pub fn line(&self) -> usize {
+        match self {
+            TaskIdIssue::Duplicate { line, .. }
+            | TaskIdIssue::Gap { line, .. }
+            | TaskIdIssue::DepthMismatch { line, .. } => *line,
+        }
+    }
+}
+
+impl fmt::Display for TaskIdIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskIdIssue::Duplicate { id, first_line, .. } => {
+                write!(f, "duplicate task {} (first on line {})", id, first_line)
+            }
+            TaskIdIssue::Gap {
+                expected, found, ..
+            } => write!(f, "expected task {} but found {}", expected, found),
+            TaskIdIssue::DepthMismatch { id, depth, .. } => write!(
+                f,
+                "task {} implies depth {} but is nested at depth {}",
+                id,
+                id.depth(),
+                depth
+            ),
+        }
+    }
+}
+
+/// タスク番号の重複・欠番・深さの不一致を検出する
+///
+/// # 引数
+/// * `tasks` - トップレベルのタスク一覧
+///
+/// # 戻り値
+/// * `Vec<TaskIdIssue>` - 出現順に並んだ問題の一覧
+pub fn check_task_ids(tasks: &[Task]) -> Vec<TaskIdIssue> {
+    let mut issues = Vec::new();
+    let mut seen: HashMap<&TaskId, usize> = HashMap::new();
+    // 親番号ごとの直前の兄弟番号
+    let mut last_sibling: HashMap<Option<TaskId>, u32> = HashMap::new();
+
+    for task in flatten_tasks(tasks) {
+        let Some(id) = &task.id else {
+            continue;
+        };
+
+        if let Some(&first_line) = seen.get(id) {
+            issues.push(TaskIdIssue::Duplicate {
+                id: id.clone(),
+                line: task.line,
+                first_line,
+            });
+            continue;
+        }
+        seen.insert(id, task.line);
+
+        let number = *id.0.last().unwrap_or(&0);
+        let previous = last_sibling.insert(id.parent(), number).unwrap_or(0);
+        if number > previous + 1 {
+            let mut expected = id.clone();
+            if let Some(last) = expected.0.last_mut() {
+                *last = previous + 1;
+            }
+            issues.push(TaskIdIssue::Gap {
+                expected,
+                found: id.clone(),
+                line: task.line,
+            });
+        }
+
+        if id.depth() != task.depth {
+            issues.push(TaskIdIssue::DepthMismatch {
+                id: id.clone(),
+                depth: task.depth,
+                line: task.line,
+            });
+        }
+    }
+
+    issues
+}
+
+/// タスクツリーを深さ優先で平坦化する
+///
+/// # 引数
+/// * `tasks` - トップレベルのタスク一覧
+///
+/// # 戻り値
+/// * `Vec<&Task>` - ファイル内の出現順に並んだ全タスク
+pub fn flatten_tasks(tasks: &[Task]) -> Vec<&Task> {
+    fn visit<'a>(tasks: &'a [Task], out: &mut Vec<&'a Task>) {
+        for task in tasks {
+            out.push(task);
+            visit(&task.children, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    visit(tasks, &mut out);
+    out
+}
+
+/// 進捗の計算方法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// すべてのチェックボックス
+    #[default]
+    All,
+    /// サブタスクを持たないタスクのみ
+    LeafOnly,
+    /// トップレベルのタスクのみ
+    TopLevelOnly,
+}
+
+impl ProgressMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProgressMode::All => "all tasks",
+            ProgressMode::LeafOnly => "leaf tasks",
+            ProgressMode::TopLevelOnly => "top-level tasks",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            ProgressMode::All => ProgressMode::LeafOnly,
+            ProgressMode::LeafOnly => ProgressMode::TopLevelOnly,
+            ProgressMode::TopLevelOnly => ProgressMode::All,
+        }
+    }
+}
+
+/// 進捗の計算対象となるタスク
+fn progress_tasks(tasks: &[Task], mode: ProgressMode) -> Vec<&Task> {
+    match mode {
+        ProgressMode::All => flatten_tasks(tasks),
+        ProgressMode::LeafOnly => flatten_tasks(tasks)
+            .into_iter()
+            .filter(|task| task.children.is_empty())
+            .collect(),
+        ProgressMode::TopLevelOnly => tasks.iter().collect(),
+    }
+}
+
+/// タスクツリーから (総タスク数, 完了タスク数) を数える
+pub fn count_tasks(tasks: &[Task], mode: ProgressMode) -> (usize, usize) {
+    let counted = progress_tasks(tasks, mode);
+    let completed = counted.iter().filter(|task| task.is_completed()).count();
+    (counted.len(), completed)
+}
+
+/// 実行中のタスク数を数える
+pub fn count_in_progress_tasks(tasks: &[Task]) -> usize {
+    flatten_tasks(tasks)
+        .iter()
+        .filter(|task| task.is_in_progress())
+        .count()
+}
+
+/// 現在実行中のタスクを返す
+///
+/// 親子がともに実行中の場合は、実行中の子を持たない最も深いタスクを選ぶ。
+pub fn current_task(tasks: &[Task]) -> Option<&Task> {
+    flatten_tasks(tasks).into_iter().find(|task| {
+        task.is_in_progress() && !task.children.iter().any(|child| child.is_in_progress())
+    })
+}
+
+/// 任意タスクを除いた (総タスク数, 完了タスク数) を数える
+pub fn count_required_tasks(tasks: &[Task], mode: ProgressMode) -> (usize, usize) {
+    let required: Vec<&Task> = progress_tasks(tasks, mode)
+        .into_iter()
+        .filter(|task| !task.optional)
+        .collect();
+    let completed = required.iter().filter(|task| task.is_completed()).count();
+    (required.len(), completed)
+}
+
+/// 任意タスクを除き、見積もりのあるタスクの (総工数, 完了工数) を時間で合計する
+pub fn count_effort(tasks: &[Task], mode: ProgressMode) -> (f64, f64) {
+    progress_tasks(tasks, mode)
+        .into_iter()
+        .filter(|task| !task.optional)
+        .filter_map(|task| task.estimate.map(|hours| (hours, task.is_completed())))
+        .fold((0.0, 0.0), |(total, completed), (hours, done)| {
+            (
+                total + hours,
+                if done { completed + hours } else { completed },
+            )
+        })
+}
+
+/// 親タスクとサブタスクの完了状態の食い違い
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompletionIssue {
+    /// 親タスクが完了しているのに未完了のサブタスクがある
+    OpenChildren {
+        task: String,
+        line: usize,
+        open: usize,
+        total: usize,
+    },
+    /// サブタスクがすべて完了しているのに親タスクが未完了
+    OpenParent { task: String, line: usize },
+}
+
+impl CompletionIssue {
+    /// 問題が見つかった行番号（親タスクの行）
+    pub fn line(&self) -> usize {
+        match self {
+            CompletionIssue::OpenChildren { line, .. }
+            | CompletionIssue::OpenParent { line, .. } => *line,
+        }
+    }
+}
+
+impl fmt::Display for CompletionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompletionIssue::OpenChildren {
+                task, open, total, ..
+            } => write!(
+                f,
+                "task {} is checked but {} of {} sub-tasks are open",
+                task, open, total
+            ),
+            CompletionIssue::OpenParent { task, .. } => {
+                write!(
+                    f,
+                    "all sub-tasks of {} are done but it is not checked",
+                    task
+                )
+            }
+        }
+    }
+}
+
+/// 親タスクとサブタスクの完了状態の食い違いを検出する
+///
+/// 任意タスク（`- [ ]*`）のサブタスクは未完了でも問題として扱わない。
+///
+/// # 引数
+/// * `tasks` - トップレベルのタスク一覧
+///
+/// # 戻り値
+/// * `Vec<CompletionIssue>` - ファイル内の出現順に並んだ問題の一覧
+pub fn check_completion(tasks: &[Task]) -> Vec<CompletionIssue> {
+    let mut issues = Vec::new();
+
+    for task in flatten_tasks(tasks) {
+        let required: Vec<&Task> = task.children.iter().filter(|c| !c.optional).collect();
+        if required.is_empty() {
+            continue;
+        }
+        let label = match &task.id {
+            Some(id) => id.to_string(),
+            None => format!("\"{}\"", task.title),
+        };
+        let open = required.iter().filter(|c| !c.is_completed()).count();
+
+        if task.is_completed() && open > 0 {
+            issues.push(CompletionIssue::OpenChildren {
+                task: label,
+                line: task.line,
+                open,
+                total: required.len(),
+            });
+        } else if !task.is_completed() && open == 0 {
+            issues.push(CompletionIssue::OpenParent {
+                task: label,
+                line: task.line,
+            });
+        }
+    }
+
+    issues
+}
+
+/// タスクとして認識されなかった行の診断
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    // 行番号（1 始まり）
+    pub line: usize,
+    // 列番号（1 始まり、文字単位）
+    pub column: usize,
+    // 問題の説明
+    pub message: String,
+    // 修正後の行の候補
+    pub suggestion: String,
+}
+
+/// tasks.md を読み込んでタスクツリーを構築する
+///
+/// # 引数
+/// * `path` - tasks.md のパス
+///
+/// # 戻り値
+/// * `Ok((Vec<Task>, Vec<Diagnostic>))` - トップレベルのタスク一覧と、
+///   タスクとして認識されなかった行の診断（ファイル不在時はどちらも空）
+pub fn parse_tasks_file(path: &Path) -> Result<(Vec<Task>, Vec<Diagnostic>)> {
     if !path.exists() {
-        return Ok((0, 0));
+        return Ok((Vec::new(), Vec::new()));
     }
 
     let content = fs::read_to_string(path)?;
+    let tasks = parse_tasks(&content);
+    let diagnostics = diagnose_tasks(&content, &tasks);
+
+    Ok((tasks, diagnostics))
+}
+
+/// タスクになり損ねたチェックボックス行を検出する
+///
+/// `-[ ] foo`（マーカー直後の空白なし）、`- [ x] foo` / `- []`（不正なチェックボックス）、
+/// タブやコードブロック扱いになるインデントのチェックボックスを対象とする。
+/// コードブロック・HTML コメント・引用・フロントマター内の行は対象外。
+///
+/// # 引数
+/// * `content` - tasks.md の内容
+/// * `tasks` - `content` から構築したタスクツリー
+///
+/// # 戻り値
+/// * `Vec<Diagnostic>` - 行番号順に並んだ診断の一覧
+pub fn diagnose_tasks(content: &str, tasks: &[Task]) -> Vec<Diagnostic> {
+    let task_lines: HashSet<usize> = flatten_tasks(tasks).iter().map(|task| task.line).collect();
+    let mut diagnostics = Vec::new();
+    let mut fence: Option<&str> = None;
+    let mut in_comment = false;
+    let mut in_front_matter = false;
+
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim_end_matches('\r');
+        let trimmed = line.trim_start();
+
+        if index == 0 && line == "---" {
+            in_front_matter = true;
+            continue;
+        }
+        if in_front_matter {
+            in_front_matter = !(line == "---" || line == "...");
+            continue;
+        }
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            continue;
+        }
+        if in_comment || trimmed.starts_with("<!--") {
+            in_comment = !line.contains("-->");
+            continue;
+        }
+        if trimmed.starts_with('>') || task_lines.contains(&(index + 1)) {
+            continue;
+        }
 
-    if content.is_empty() {
-        return Ok((0, 0));
+        if let Some(diagnostic) = diagnose_line(line, index + 1) {
+            diagnostics.push(diagnostic);
+        }
     }
 
-    let mut total = 0;
-    let mut completed = 0;
+    diagnostics
+}
+
+/// 1 行を診断する
+fn diagnose_line(line: &str, line_number: usize) -> Option<Diagnostic> {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    let column = |offset: usize| line[..offset].chars().count() + 1;
+
+    // リストマーカーの長さ（`-` / `*` / `+` / `1.` / `1)`）
+    let marker_len = if trimmed.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = trimmed
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len());
+        if digits == 0 || digits > 9 || !trimmed[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        digits + 1
+    };
+    let (marker, after_marker) = trimmed.split_at(marker_len);
+    let checkbox = after_marker.trim_start();
+    let (inner, title) = split_checkbox_like(checkbox)?;
+    let checkbox_offset = line.len() - checkbox.len();
+
+    // チェックボックスの中身を正規化（認識できない状態は対象外）
+    let normalized = match inner.trim() {
+        "" => ' ',
+        "x" | "X" => 'x',
+        "-" => '-',
+        _ => return None,
+    };
+    let fixed = format!("{}{} [{}] {}", indent, marker, normalized, title.trim())
+        .trim_end()
+        .to_string();
+
+    let message = if after_marker.len() == checkbox.len() {
+        "missing space after list marker".to_string()
+    } else if inner.len() != 1 {
+        format!("malformed checkbox `[{}]`", inner)
+    } else if indent.contains('\t') {
+        let suggestion = format!("{}{}", indent.replace('\t', "  "), trimmed);
+        return Some(Diagnostic {
+            line: line_number,
+            column: 1,
+            message: "tab-indented checkbox is not recognized as a task".to_string(),
+            suggestion,
+        });
+    } else if indent.len() >= 4 {
+        return Some(Diagnostic {
+            line: line_number,
+            column: 1,
+            message: "checkbox indented by 4+ spaces is parsed as a code block".to_string(),
+            suggestion: trimmed.to_string(),
+        });
+    } else {
+        return None;
+    };
+
+    Some(Diagnostic {
+        line: line_number,
+        column: column(checkbox_offset),
+        message,
+        suggestion: fixed,
+    })
+}
+
+/// `[...]` で始まるチェックボックスらしき部分を (中身, 残り) に分解する
+///
+/// リンク（`[text](url)` / `[text][ref]`）や長いテキストは対象外とする。
+fn split_checkbox_like(text: &str) -> Option<(&str, &str)> {
+    let rest = text.strip_prefix('[')?;
+    let close = rest.find(']')?;
+    let (inner, after) = (&rest[..close], &rest[close + 1..]);
+    if inner.chars().count() > 3 || after.starts_with(['(', '[', ':']) {
+        return None;
+    }
+    Some((inner, after))
+}
+
+/// Markdown テキストからタスクツリーを構築する
+///
+/// CommonMark として解析し、リスト項目の先頭にあるチェックボックスだけを
+/// タスクとして扱う。コードブロック・引用・HTML コメント内の行は無視する。
+/// 親子関係はリストの入れ子で判定し、タスク項目内の子タスク以外の行は
+/// そのタスクの説明として扱う。
+pub fn parse_tasks(content: &str) -> Vec<Task> {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|&start| start <= offset) - 1;
+    let line_text = |line: usize| {
+        let end = line_starts
+            .get(line + 1)
+            .map_or(content.len(), |&next| next - 1);
+        content[line_starts[line]..end].trim_end_matches('\r')
+    };
+
+    let mut roots: Vec<Task> = Vec::new();
+    // 開いているリスト項目（タスクでない項目は None）
+    let mut open_items: Vec<Option<OpenTask>> = Vec::new();
+    let mut quote_depth = 0usize;
+
+    for (event, range) in
+        Parser::new_ext(content, Options::ENABLE_YAML_STYLE_METADATA_BLOCKS).into_offset_iter()
+    {
+        match event {
+            Event::Start(Tag::BlockQuote(_)) => quote_depth += 1,
+            Event::End(TagEnd::BlockQuote(_)) => quote_depth = quote_depth.saturating_sub(1),
+            Event::Start(Tag::Item) => {
+                // タブでインデントされた項目は直前の改行から始まる範囲で報告される
+                let start = if content[range.start..].starts_with('\n') {
+                    range.start + 1
+                } else {
+                    range.start
+                };
+                let first_line = line_of(start);
+                let checkbox = if quote_depth == 0 {
+                    strip_list_marker(&content[start..line_end(content, start)])
+                        .and_then(parse_checkbox)
+                } else {
+                    None
+                };
+
+                let Some((status, title)) = checkbox else {
+                    open_items.push(None);
+                    continue;
+                };
+
+                let (optional, title) = split_optional_marker(title);
+                let (id, title) = match split_task_id(title) {
+                    Some((id, rest)) => (Some(id), rest),
+                    None => (None, title),
+                };
+                let depth = open_items.iter().flatten().count();
+
+                let task = Task {
+                    id,
+                    title: title.to_string(),
+                    status,
+                    optional,
+                    depth,
+                    line: first_line + 1,
+                    description: Vec::new(),
+                    estimate: None,
+                    children: Vec::new(),
+                };
+
+                match last_task_at(&mut roots, depth) {
+                    Some(parent) => parent.children.push(task),
+                    None => roots.push(task),
+                }
+                open_items.push(Some(OpenTask {
+                    depth,
+                    first_line,
+                    last_line: line_of(range.end.saturating_sub(1)),
+                    child_lines: Vec::new(),
+                }));
+            }
+            Event::End(TagEnd::Item) => {
+                let Some(Some(open)) = open_items.pop() else {
+                    continue;
+                };
+
+                let description = (open.first_line + 1..=open.last_line)
+                    .filter(|line| !open.child_lines.iter().any(|child| child.contains(line)))
+                    .map(|line| line_text(line).trim())
+                    .filter(|line| !line.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                if let Some(task) = last_task_at(&mut roots, open.depth + 1) {
+                    task.description = description;
+                }
+
+                // 子タスクの行は親タスクの説明から除外する
+                if let Some(parent) = open_items.iter_mut().rev().flatten().next() {
+                    parent.child_lines.push(open.first_line..=open.last_line);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    roots
+}
+
+/// 解析中のタスク項目
+struct OpenTask {
+    depth: usize,
+    first_line: usize,
+    last_line: usize,
+    // 子タスク項目が占める行範囲
+    child_lines: Vec<RangeInclusive<usize>>,
+}
+
+/// `offset` を含む行の終端位置を返す
+fn line_end(content: &str, offset: usize) -> usize {
+    content[offset..]
+        .find('\n')
+        .map_or(content.len(), |end| offset + end)
+}
+
+/// 行頭のリストマーカー（`-` / `*` / `+` / `1.` / `1)`）を取り除く
+fn strip_list_marker(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let rest = match trimmed.strip_prefix(['-', '*', '+']) {
+        Some(rest) => rest,
+        None => {
+            let digits = trimmed
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(trimmed.len());
+            if digits == 0 || digits > 9 {
+                return None;
+            }
+            trimmed[digits..].strip_prefix(['.', ')'])?
+        }
+    };
+
+    rest.starts_with([' ', '\t']).then(|| rest.trim_start())
+}
+
+/// チェックボックスを (状態, タイトル) に分解する
+fn parse_checkbox(text: &str) -> Option<(TaskStatus, &str)> {
+    let rest = text.strip_prefix('[')?;
+    let mut chars = rest.chars();
+    let status = match chars.next()? {
+        ' ' => TaskStatus::Todo,
+        '-' => TaskStatus::InProgress,
+        'x' | 'X' => TaskStatus::Done,
+        _ => return None,
+    };
+
+    chars
+        .as_str()
+        .strip_prefix(']')
+        .map(|title| (status, title.trim()))
+}
 
-    for line in content.lines() {
-        let trimmed = line.trim();
-        if trimmed.starts_with("- [x]") {
-            completed += 1;
-            total += 1;
-        } else if trimmed.starts_with("- [ ]") {
-            total += 1;
+/// チェックボックス直後の任意タスクマーカー（`*` / `\*`）を取り除く
+fn split_optional_marker(title: &str) -> (bool, &str) {
+    match title
+        .strip_prefix("\\*")
+        .or_else(|| title.strip_prefix('*'))
+    {
+        Some(rest) if rest.is_empty() || rest.starts_with(char::is_whitespace) => {
+            (true, rest.trim_start())
         }
+        _ => (false, title),
+    }
+}
+
+/// タイトル先頭の `1.` / `1.1` 形式の番号を切り出す
+///
+/// 番号にはドットが 1 つ以上含まれている必要がある（`2024 roadmap` は番号として扱わない）。
+fn split_task_id(title: &str) -> Option<(TaskId, &str)> {
+    let end = title
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(title.len());
+    let (number, rest) = title.split_at(end);
+
+    if !number.contains('.') || !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+        return None;
+    }
+
+    let segments = number
+        .strip_suffix('.')
+        .unwrap_or(number)
+        .split('.')
+        .map(|part| part.parse::<u32>().ok())
+        .collect::<Option<Vec<u32>>>()?;
+
+    Some((TaskId(segments), rest.trim()))
+}
+
+/// `_Requirements: 1.1, 2.3_` / `_要件: 1.1_` から番号リスト部分を取り出す
+fn requirement_ref_list(line: &str) -> Option<&str> {
+    let lower = line.to_ascii_lowercase();
+    let start = ["requirements:", "requirement:", "要件:", "要件："]
+        .iter()
+        .find_map(|marker| lower.find(marker).map(|pos| pos + marker.len()))?;
+
+    Some(line[start..].trim().trim_end_matches(['_', '*']))
+}
+
+/// `1` / `2.3` のような数字とドットのみの文字列か判定する
+fn is_dotted_number(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// 最新のタスクパスを `levels` 階層たどった先のタスクを返す
+///
+/// `levels` が 0 のときは親が存在しないため `None` を返す。
+fn last_task_at(roots: &mut [Task], levels: usize) -> Option<&mut Task> {
+    if levels == 0 {
+        return None;
     }
 
-    Ok((total, completed))
+    let mut current = roots.last_mut()?;
+    for _ in 1..levels {
+        current = current.children.last_mut()?;
+    }
+    Some(current)
 }
 
 #[cfg(test)]
@@ -35,7 +802,7 @@ mod tests {
 
     use tempfile::TempDir;
 
-    use super::parse_tasks_file;
+    use super::*;
 
     #[test]
     fn test_parse_tasks_file_not_exist() {
@@ -44,7 +811,8 @@ mod tests {
         let user_auth_dir = specs_dir.join("user-auth");
         fs::create_dir_all(&user_auth_dir).unwrap();
 
-        let (total, completed) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (tasks, _) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (total, completed) = count_tasks(&tasks, ProgressMode::All);
 
         assert_eq!(total, 0);
         assert_eq!(completed, 0);
@@ -58,7 +826,8 @@ mod tests {
         fs::create_dir_all(&user_auth_dir).unwrap();
         fs::write(user_auth_dir.join("tasks.md"), "").unwrap();
 
-        let (total, completed) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (tasks, _) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (total, completed) = count_tasks(&tasks, ProgressMode::All);
 
         assert_eq!(total, 0);
         assert_eq!(completed, 0);
@@ -76,9 +845,350 @@ mod tests {
         )
         .unwrap();
 
-        let (total, completed) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (tasks, _) = parse_tasks_file(&user_auth_dir.join("tasks.md")).unwrap();
+        let (total, completed) = count_tasks(&tasks, ProgressMode::All);
 
         assert_eq!(total, 5);
         assert_eq!(completed, 1);
     }
+
+    #[test]
+    fn test_parse_tasks_builds_tree() {
+        let content = "# Plan\n\n- [ ] Task 1\n- [ ] Task 2\n  - [ ] Task 2.1\n    - [x] Task 2.1.1\n  - [x] Task 2.2\n- [ ] Task 3";
+
+        let tasks = parse_tasks(content);
+
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(tasks[1].title, "Task 2");
+        assert_eq!(tasks[1].line, 4);
+        assert_eq!(tasks[1].children.len(), 2);
+
+        let sub = &tasks[1].children[0];
+        assert_eq!(sub.title, "Task 2.1");
+        assert_eq!(sub.depth, 1);
+        assert_eq!(sub.children[0].title, "Task 2.1.1");
+        assert_eq!(sub.children[0].depth, 2);
+        assert!(sub.children[0].is_completed());
+
+        assert!(tasks[1].children[1].is_completed());
+        assert_eq!(tasks[2].depth, 0);
+    }
+
+    #[test]
+    fn test_parse_tasks_collects_description() {
+        let content = "- [x] 1. Define models\n\n  - Create Task struct\n  - _Requirements: 2.1_\n\n- [ ] 2. Parser\n  - [ ] 2.1 Tests\n    - Property test\n";
+
+        let tasks = parse_tasks(content);
+
+        assert_eq!(
+            tasks[0].description,
+            vec!["- Create Task struct", "- _Requirements: 2.1_"]
+        );
+        assert!(tasks[1].description.is_empty());
+        assert_eq!(tasks[1].children[0].description, vec!["- Property test"]);
+    }
+
+    #[test]
+    fn test_parse_tasks_heading_ends_description() {
+        let content = "- [ ] Task 1\n  detail\n## Next\n  orphan\n- [ ] Task 2";
+
+        let tasks = parse_tasks(content);
+
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].description, vec!["detail"]);
+        assert!(tasks[1].description.is_empty());
+    }
+
+    #[test]
+    fn test_parse_tasks_extracts_ids() {
+        let content = "- [ ] 1. Set up project\n  - [ ] 1.1 Create models\n  - [x] 1.2. Add tests\n- [ ] 2024 roadmap";
+
+        let tasks = parse_tasks(content);
+
+        assert_eq!(tasks[0].id, Some(TaskId(vec![1])));
+        assert_eq!(tasks[0].title, "Set up project");
+        assert_eq!(tasks[0].children[0].id, Some(TaskId(vec![1, 1])));
+        assert_eq!(tasks[0].children[0].title, "Create models");
+        assert_eq!(tasks[0].children[1].id.as_ref().unwrap().to_string(), "1.2");
+        assert_eq!(tasks[1].id, None);
+        assert_eq!(tasks[1].title, "2024 roadmap");
+    }
+
+    #[test]
+    fn test_check_task_ids_clean() {
+        let tasks = parse_tasks("- [ ] 1. A\n  - [ ] 1.1 B\n  - [ ] 1.2 C\n- [ ] 2. D");
+
+        assert!(check_task_ids(&tasks).is_empty());
+    }
+
+    #[test]
+    fn test_check_task_ids_reports_issues() {
+        let tasks =
+            parse_tasks("- [ ] 1. A\n  - [ ] 1.1 B\n  - [ ] 1.1 C\n- [ ] 3. D\n- [ ] 3.1 E");
+
+        let issues = check_task_ids(&tasks);
+
+        assert_eq!(
+            issues,
+            vec![
+                TaskIdIssue::Duplicate {
+                    id: TaskId(vec![1, 1]),
+                    line: 3,
+                    first_line: 2,
+                },
+                TaskIdIssue::Gap {
+                    expected: TaskId(vec![2]),
+                    found: TaskId(vec![3]),
+                    line: 4,
+                },
+                TaskIdIssue::DepthMismatch {
+                    id: TaskId(vec![3, 1]),
+                    depth: 0,
+                    line: 5,
+                },
+            ]
+        );
+        assert_eq!(issues[1].to_string(), "expected task 2 but found 3");
+    }
+
+    #[test]
+    fn test_parse_tasks_ignores_code_quotes_and_comments() {
+        let content = "- [ ] Real\n\n```markdown\n- [ ] In code\n```\n\n> - [x] Quoted\n\n<!--\n- [ ] Commented\n-->\n\n    - [ ] Indented code\n\nText mentioning - [ ] inline\n\n- [x] Also real";
+
+        let tasks = parse_tasks(content);
+        let titles: Vec<&str> = tasks.iter().map(|task| task.title.as_str()).collect();
+
+        assert_eq!(titles, vec!["Real", "Also real"]);
+        assert_eq!(tasks[1].line, 17);
+    }
+
+    #[test]
+    fn test_parse_tasks_alternative_list_markers() {
+        let content = "* [ ] Star\n\n+ [x] Plus\n\n1. [ ] Ordered\n2) [-] Paren\n\n- Not a task\n  - [ ] Nested under plain item";
+
+        let tasks = parse_tasks(content);
+        let titles: Vec<&str> = tasks.iter().map(|task| task.title.as_str()).collect();
+
+        assert_eq!(
+            titles,
+            vec![
+                "Star",
+                "Plus",
+                "Ordered",
+                "Paren",
+                "Nested under plain item"
+            ]
+        );
+        assert_eq!(tasks[3].status, TaskStatus::InProgress);
+        assert_eq!(tasks[4].depth, 0);
+    }
+
+    #[test]
+    fn test_parse_tasks_description_skips_code_fences_in_items() {
+        let content = "- [ ] 1. Setup\n  ```sh\n  - [ ] not a task\n  ```\n  - [ ] 1.1 Child\n    child note\n\n  trailing note";
+
+        let tasks = parse_tasks(content);
+
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].children.len(), 1);
+        assert_eq!(
+            tasks[0].description,
+            vec!["```sh", "- [ ] not a task", "```", "trailing note"]
+        );
+        assert_eq!(tasks[0].children[0].description, vec!["child note"]);
+    }
+
+    #[test]
+    fn test_parse_tasks_checkbox_states() {
+        let tasks = parse_tasks("- [ ] A\n- [x] B\n- [X] C\n- [-] D\n- [?] E");
+
+        let statuses: Vec<TaskStatus> = tasks.iter().map(|task| task.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                TaskStatus::Todo,
+                TaskStatus::Done,
+                TaskStatus::Done,
+                TaskStatus::InProgress,
+            ]
+        );
+        assert_eq!(count_tasks(&tasks, ProgressMode::All), (4, 2));
+        assert_eq!(count_in_progress_tasks(&tasks), 1);
+    }
+
+    #[test]
+    fn test_current_task_prefers_deepest() {
+        let tasks = parse_tasks("- [x] 1. A\n- [-] 2. B\n  - [x] 2.1 C\n  - [-] 2.2 D\n- [-] 3. E");
+
+        assert_eq!(current_task(&tasks).unwrap().title, "D");
+        assert!(current_task(&parse_tasks("- [ ] A\n- [x] B")).is_none());
+    }
+
+    #[test]
+    fn test_parse_tasks_optional_marker() {
+        let content = "- [ ] 1. Required\n- [ ]* 2. Unit tests\n- [x] \\* 3. Escaped\n- [ ] *emphasis* is not a marker";
+
+        let tasks = parse_tasks(content);
+
+        assert!(!tasks[0].optional);
+        assert!(tasks[1].optional);
+        assert_eq!(tasks[1].id, Some(TaskId(vec![2])));
+        assert_eq!(tasks[1].title, "Unit tests");
+        assert!(tasks[2].optional);
+        assert_eq!(tasks[2].title, "Escaped");
+        assert!(!tasks[3].optional);
+        assert_eq!(tasks[3].title, "*emphasis* is not a marker");
+    }
+
+    #[test]
+    fn test_count_required_tasks() {
+        let tasks = parse_tasks("- [x] 1. A\n- [ ]* 2. B\n  - [x]* 2.1 C\n- [ ] 3. D");
+
+        assert_eq!(count_tasks(&tasks, ProgressMode::All), (4, 2));
+        assert_eq!(count_required_tasks(&tasks, ProgressMode::All), (2, 1));
+    }
+
+    #[test]
+    fn test_count_effort() {
+        let mut tasks = parse_tasks("- [x] 1. A\n- [ ] 2. B\n- [ ]* 3. C\n- [ ] 4. D");
+        tasks[0].estimate = Some(2.0);
+        tasks[1].estimate = Some(6.0);
+        tasks[2].estimate = Some(4.0);
+
+        // 任意タスクと見積もりのないタスクは工数に含めない
+        assert_eq!(count_effort(&tasks, ProgressMode::All), (8.0, 2.0));
+        assert_eq!(count_effort(&[], ProgressMode::All), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_count_tasks_by_progress_mode() {
+        let tasks = parse_tasks(
+            "- [x] 1. A\n  - [x] 1.1 B\n  - [ ] 1.2 C\n- [ ] 2. D\n- [x]* 3. E\n  - [x] 3.1 F",
+        );
+
+        assert_eq!(count_tasks(&tasks, ProgressMode::All), (6, 4));
+        // 1.1, 1.2, 2, 3.1
+        assert_eq!(count_tasks(&tasks, ProgressMode::LeafOnly), (4, 2));
+        assert_eq!(count_tasks(&tasks, ProgressMode::TopLevelOnly), (3, 2));
+        assert_eq!(
+            count_required_tasks(&tasks, ProgressMode::TopLevelOnly),
+            (2, 1)
+        );
+    }
+
+    #[test]
+    fn test_check_completion() {
+        let tasks = parse_tasks(
+            "- [x] 1. A\n  - [x] 1.1 B\n  - [ ] 1.2 C\n- [ ] 2. D\n  - [x] 2.1 E\n  - [ ]* 2.2 F\n- [x] 3. G\n  - [x] 3.1 H\n  - [ ]* 3.2 I\n- [-] Untitled\n  - [x] J",
+        );
+
+        let issues = check_completion(&tasks);
+
+        assert_eq!(
+            issues,
+            vec![
+                CompletionIssue::OpenChildren {
+                    task: "1".to_string(),
+                    line: 1,
+                    open: 1,
+                    total: 2,
+                },
+                CompletionIssue::OpenParent {
+                    task: "2".to_string(),
+                    line: 4,
+                },
+                CompletionIssue::OpenParent {
+                    task: "\"Untitled\"".to_string(),
+                    line: 10,
+                },
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "task 1 is checked but 1 of 2 sub-tasks are open"
+        );
+    }
+
+    #[test]
+    fn test_diagnose_malformed_checkboxes() {
+        let content = "# Tasks\n\n-[ ] 1. No space\n- [ x] 2. Padded\n- []\n- [x] 3. Valid\n\tnot a list\n- [link](url)\n";
+
+        let tasks = parse_tasks(content);
+        let diagnostics = diagnose_tasks(content, &tasks);
+
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic {
+                    line: 3,
+                    column: 2,
+                    message: "missing space after list marker".to_string(),
+                    suggestion: "- [ ] 1. No space".to_string(),
+                },
+                Diagnostic {
+                    line: 4,
+                    column: 3,
+                    message: "malformed checkbox `[ x]`".to_string(),
+                    suggestion: "- [x] 2. Padded".to_string(),
+                },
+                Diagnostic {
+                    line: 5,
+                    column: 3,
+                    message: "malformed checkbox `[]`".to_string(),
+                    suggestion: "- [ ]".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_indented_checkboxes() {
+        // 親タスクのないタブ・4 スペースのインデントはコードブロックになる
+        let content = "Intro\n\n\t- [ ] 1. Tabbed\n\n    - [x] 2. Indented\n\n- [ ] 3. Parent\n\t- [ ] 3.1 Nested\n";
+
+        let tasks = parse_tasks(content);
+        let diagnostics = diagnose_tasks(content, &tasks);
+
+        assert_eq!(count_tasks(&tasks, ProgressMode::All), (2, 0));
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].line, 3);
+        assert_eq!(
+            diagnostics[0].message,
+            "tab-indented checkbox is not recognized as a task"
+        );
+        assert_eq!(diagnostics[0].suggestion, "  - [ ] 1. Tabbed");
+        assert_eq!(diagnostics[1].line, 5);
+        assert_eq!(diagnostics[1].suggestion, "- [x] 2. Indented");
+    }
+
+    #[test]
+    fn test_diagnose_ignores_code_comments_and_quotes() {
+        let content = "---\nowner: -[ ] x\n---\n```\n-[ ] code\n```\n<!--\n- [ x] comment\n-->\n> -[ ] quote\n";
+
+        assert!(diagnose_tasks(content, &parse_tasks(content)).is_empty());
+    }
+
+    #[test]
+    fn test_task_requirement_refs() {
+        let tasks = parse_tasks(
+            "- [ ] 1. Models\n  - Create struct\n  - _Requirements: 1.1, 2.3_\n- [ ] 2. 実装\n  - _要件: 3.1、3.2_\n- [ ] 3. None",
+        );
+
+        assert_eq!(tasks[0].requirement_refs(), vec!["1.1", "2.3"]);
+        assert_eq!(tasks[1].requirement_refs(), vec!["3.1", "3.2"]);
+        assert!(tasks[2].requirement_refs().is_empty());
+    }
+
+    #[test]
+    fn test_flatten_tasks_preserves_order() {
+        let content = "- [ ] A\n  - [ ] B\n- [ ] C";
+
+        let tasks = parse_tasks(content);
+        let titles: Vec<&str> = flatten_tasks(&tasks)
+            .iter()
+            .map(|task| task.title.as_str())
+            .collect();
+
+        assert_eq!(titles, vec!["A", "B", "C"]);
+    }
 }
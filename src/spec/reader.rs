@@ -1,27 +1,35 @@
-use std::{fs, path::PathBuf};
+use std::{cell::RefCell, collections::HashMap, fs, path::PathBuf, rc::Rc};
 
-use crate::spec::finder::SpecSet;
-
-/// Spec ファイルの内容を保持する構造体
-#[derive(Debug)]
-pub struct SpecContent {
-    pub requirements: Option<String>,
-    pub design: Option<String>,
-    pub tasks: Option<String>,
+/// 読み込んだ Spec ファイルの内容のキャッシュ
+///
+/// 描画やスクロールのたびにファイルを読み直さないよう、一度読んだ内容をパスごとに保持する。
+/// ファイルの変更は検出しないため、再読み込み時に `clear` で明示的に破棄する。
+#[derive(Debug, Default)]
+pub struct DocumentCache {
+    // パスごとのファイルの内容（読み込めなかったファイルは `None`）
+    documents: RefCell<HashMap<PathBuf, Option<Rc<str>>>>,
 }
 
-/// Spec セットの全ファイルを読み込む
-///
-/// # 引数
-/// * `spec_set` - 読み込む Spec セット
-///
-/// # 戻り値
-/// * `SpecContent` - 読み込んだファイルの内容
-pub fn read_spec_content(spec_set: &SpecSet) -> SpecContent {
-    SpecContent {
-        requirements: read_spec_file(&spec_set.requirements),
-        design: read_spec_file(&spec_set.design),
-        tasks: read_spec_file(&spec_set.tasks),
+impl DocumentCache {
+    /// キャッシュ済みの内容を返すか、ファイルを読み込んでキャッシュする
+    ///
+    /// # 引数
+    /// * `path` - ファイルパス（Option）
+    ///
+    /// # 戻り値
+    /// * `Option<Rc<str>>` - ファイルの内容（読み込み成功時）
+    pub fn read(&self, path: &Option<PathBuf>) -> Option<Rc<str>> {
+        let key = path.as_ref()?;
+        self.documents
+            .borrow_mut()
+            .entry(key.clone())
+            .or_insert_with(|| read_spec_file(path).map(Rc::from))
+            .clone()
+    }
+
+    /// キャッシュしたすべての内容を破棄する
+    pub fn clear(&mut self) {
+        self.documents.get_mut().clear();
     }
 }
 
@@ -72,79 +80,30 @@ mod tests {
     }
 
     #[test]
-    fn test_read_spec_content_all_files() {
+    fn test_document_cache_reads_each_file_once() {
         let temp_dir = TempDir::new().unwrap();
-        let spec_dir = temp_dir.path().join("test-spec");
-        fs::create_dir_all(&spec_dir).unwrap();
-
-        let req_path = spec_dir.join("requirements.md");
-        let design_path = spec_dir.join("design.md");
-        let tasks_path = spec_dir.join("tasks.md");
-
-        fs::write(&req_path, "# Requirements").unwrap();
-        fs::write(&design_path, "# Design").unwrap();
-        fs::write(&tasks_path, "# Tasks").unwrap();
-
-        let spec_set = SpecSet {
-            name: "test-spec".to_string(),
-            requirements: Some(req_path),
-            design: Some(design_path),
-            tasks: Some(tasks_path),
-            total_tasks: Some(0),
-            completed_tasks: Some(0),
-        };
-
-        let content = read_spec_content(&spec_set);
-
-        assert!(content.requirements.is_some());
-        assert_eq!(content.requirements.unwrap(), "# Requirements");
-        assert!(content.design.is_some());
-        assert_eq!(content.design.unwrap(), "# Design");
-        assert!(content.tasks.is_some());
-        assert_eq!(content.tasks.unwrap(), "# Tasks");
-    }
+        let path = Some(temp_dir.path().join("design.md"));
+        fs::write(path.as_ref().unwrap(), "# Design").unwrap();
+        let mut cache = DocumentCache::default();
 
-    #[test]
-    fn test_read_spec_content_missing_files() {
-        let spec_set = SpecSet {
-            name: "test-spec".to_string(),
-            requirements: None,
-            design: None,
-            tasks: None,
-            total_tasks: Some(0),
-            completed_tasks: Some(0),
-        };
-
-        let content = read_spec_content(&spec_set);
-
-        assert!(content.requirements.is_none());
-        assert!(content.design.is_none());
-        assert!(content.tasks.is_none());
+        assert_eq!(cache.read(&path).as_deref(), Some("# Design"));
+
+        // 変更はキャッシュを破棄するまで反映しない
+        fs::write(path.as_ref().unwrap(), "# Design v2").unwrap();
+        assert_eq!(cache.read(&path).as_deref(), Some("# Design"));
+
+        cache.clear();
+        assert_eq!(cache.read(&path).as_deref(), Some("# Design v2"));
     }
 
     #[test]
-    fn test_read_spec_content_partial_files() {
-        let temp_dir = TempDir::new().unwrap();
-        let spec_dir = temp_dir.path().join("test-spec");
-        fs::create_dir_all(&spec_dir).unwrap();
-
-        let req_path = spec_dir.join("requirements.md");
-        fs::write(&req_path, "# Requirements").unwrap();
-
-        let spec_set = SpecSet {
-            name: "test-spec".to_string(),
-            requirements: Some(req_path),
-            design: None,
-            tasks: Some(PathBuf::from("/nonexistent/tasks.md")),
-            total_tasks: Some(0),
-            completed_tasks: Some(0),
-        };
-
-        let content = read_spec_content(&spec_set);
-
-        assert!(content.requirements.is_some());
-        assert_eq!(content.requirements.unwrap(), "# Requirements");
-        assert!(content.design.is_none());
-        assert!(content.tasks.is_none());
+    fn test_document_cache_missing_files() {
+        let cache = DocumentCache::default();
+        assert!(cache.read(&None).is_none());
+        assert!(
+            cache
+                .read(&Some(PathBuf::from("/nonexistent/tasks.md")))
+                .is_none()
+        );
     }
 }
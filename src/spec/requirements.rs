@@ -0,0 +1,444 @@
+use std::{fs, path::Path};
+
+use color_eyre::eyre::{Ok, Result};
+
+/// requirements.md 内の要件
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Requirement {
+    // 要件番号（`1`, `2` など）
+    pub id: String,
+    // 見出しの番号以降のタイトル（無い場合は空）
+    pub title: String,
+    // 見出しの行番号（1 始まり）
+    pub line: usize,
+    // `**User Story:**` 行
+    pub user_story: Option<UserStory>,
+    // 受け入れ基準
+    pub criteria: Vec<AcceptanceCriterion>,
+}
+
+/// `As a <role>, I want <capability>, so that <benefit>` 形式のユーザーストーリー
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserStory {
+    // ラベルを除いたストーリー全文
+    pub text: String,
+    // `As a` に続く役割
+    pub role: Option<String>,
+    // `I want` に続く機能
+    pub capability: Option<String>,
+    // `so that` に続く価値
+    pub benefit: Option<String>,
+}
+
+/// 要件に属する番号付きの受け入れ基準
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AcceptanceCriterion {
+    // `要件番号.基準番号` 形式の番号（`1.2` など）
+    pub id: String,
+    // 基準の本文
+    pub text: String,
+    // 行番号（1 始まり）
+    pub line: usize,
+    // EARS 形式として解釈した結果
+    pub ears: EarsClause,
+}
+
+/// EARS（Easy Approach to Requirements Syntax）のパターン
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EarsPattern {
+    /// `THE <system> SHALL <response>`
+    #[default]
+    Ubiquitous,
+    /// `WHEN <trigger> THEN <system> SHALL <response>`
+    EventDriven,
+    /// `WHILE <state> ...`
+    StateDriven,
+    /// `IF <condition> THEN ...`
+    Unwanted,
+    /// `WHERE <feature> ...`
+    Optional,
+}
+
+impl EarsPattern {
+    /// パターンを示すキーワード
+    pub fn keyword(&self) -> &'static str {
+        match self {
+            EarsPattern::Ubiquitous => "SHALL",
+            EarsPattern::EventDriven => "WHEN",
+            EarsPattern::StateDriven => "WHILE",
+            EarsPattern::Unwanted => "IF",
+            EarsPattern::Optional => "WHERE",
+        }
+    }
+}
+
+/// EARS 形式の受け入れ基準を分解したもの
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EarsClause {
+    pub pattern: EarsPattern,
+    // WHEN / WHILE / IF / WHERE に続く条件
+    pub condition: Option<String>,
+    // SHALL の主語（`the system` など）
+    pub system: Option<String>,
+    // SHALL（または THEN）に続く応答
+    pub response: Option<String>,
+}
+
+/// requirements.md を読み込んで要件一覧を構築する
+///
+/// # 引数
+/// * `path` - requirements.md のパス
+///
+/// # 戻り値
+/// * `Ok(Vec<Requirement>)` - 要件の一覧（ファイル不在時は空）
+pub fn parse_requirements_file(path: &Path) -> Result<Vec<Requirement>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+
+    Ok(parse_requirements(&content))
+}
+
+/// Markdown テキストから要件と受け入れ基準を抽出する
+///
+/// `### Requirement N` / `### 要件 N: タイトル` 形式の見出しを要件とし、
+/// 次の同レベル以上の見出しまでにある `**User Story:**` 行をユーザーストーリー、
+/// 番号付きリストを受け入れ基準として扱う。
+/// コードブロック内の行は見出しや受け入れ基準として扱わない。
+pub fn parse_requirements(content: &str) -> Vec<Requirement> {
+    let mut requirements: Vec<Requirement> = Vec::new();
+    // 現在の要件見出しのレベル
+    let mut current_level: Option<usize> = None;
+    let mut fence: Option<&str> = None;
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(marker) = ["```", "~~~"].into_iter().find(|m| trimmed.starts_with(m)) {
+            fence = Some(marker);
+            continue;
+        }
+
+        if let Some((level, text)) = parse_heading(trimmed) {
+            if let Some((id, title)) = parse_requirement_heading(text) {
+                requirements.push(Requirement {
+                    id,
+                    title,
+                    line: index + 1,
+                    user_story: None,
+                    criteria: Vec::new(),
+                });
+                current_level = Some(level);
+            } else if current_level.is_some_and(|current| level <= current) {
+                current_level = None;
+            }
+            continue;
+        }
+
+        if current_level.is_none() || indent_width(line) > 0 {
+            continue;
+        }
+        let Some(requirement) = requirements.last_mut() else {
+            continue;
+        };
+
+        if let Some(story) = parse_user_story(trimmed) {
+            requirement.user_story.get_or_insert(story);
+        } else if let Some((number, text)) = parse_ordered_item(trimmed) {
+            requirement.criteria.push(AcceptanceCriterion {
+                id: format!("{}.{}", requirement.id, number),
+                text: text.to_string(),
+                line: index + 1,
+                ears: parse_ears(text),
+            });
+        }
+    }
+
+    requirements
+}
+
+/// 受け入れ基準の本文を EARS のパターンに分解する
+///
+/// キーワードは大文字（`WHEN` / `THEN` / `SHALL` など）のみを認識する。
+/// `SHALL` が無い場合（日本語の基準など）は `THEN` 以降を応答として扱う。
+pub fn parse_ears(text: &str) -> EarsClause {
+    let (pattern, rest) = [
+        ("WHEN", EarsPattern::EventDriven),
+        ("WHILE", EarsPattern::StateDriven),
+        ("IF", EarsPattern::Unwanted),
+        ("WHERE", EarsPattern::Optional),
+    ]
+    .iter()
+    .find_map(|(keyword, pattern)| strip_keyword(text, keyword).map(|rest| (*pattern, rest)))
+    .unwrap_or((EarsPattern::Ubiquitous, text));
+
+    let (condition, consequence) = match find_keyword(rest, "THEN") {
+        Some((before, after)) => (Some(before), after),
+        None if pattern == EarsPattern::Ubiquitous => (None, rest),
+        None => match rest.split_once(',') {
+            Some((before, after)) => (Some(before), after),
+            None => (None, rest),
+        },
+    };
+
+    let (system, response) = match find_keyword(consequence, "SHALL") {
+        Some((before, after)) => (Some(before), after),
+        None => (None, consequence),
+    };
+
+    EarsClause {
+        pattern,
+        condition: non_empty(condition),
+        system: non_empty(system),
+        response: non_empty(Some(response)),
+    }
+}
+
+/// `**User Story:**` / `**ユーザーストーリー**:` 行を解析する
+fn parse_user_story(trimmed: &str) -> Option<UserStory> {
+    let text = trimmed.trim_start_matches(['*', '_']);
+    let rest = ["User Story", "ユーザーストーリー"]
+        .iter()
+        .find_map(|label| text.strip_prefix(label))?;
+    let text = rest
+        .trim_start_matches(['*', '_', ':', '：'])
+        .trim_start_matches(['*', '_'])
+        .trim();
+
+    let lower = text.to_ascii_lowercase();
+    let role_start = ["as an ", "as a "]
+        .iter()
+        .find_map(|prefix| lower.starts_with(prefix).then_some(prefix.len()));
+    let want = lower.find("i want");
+    let so_that = lower.find("so that");
+
+    let role = match (role_start, want) {
+        (Some(start), Some(end)) if start <= end => Some(&text[start..end]),
+        _ => None,
+    };
+    let capability = want.map(|start| {
+        let start = start + "i want".len();
+        match so_that {
+            Some(end) if end >= start => &text[start..end],
+            _ => &text[start..],
+        }
+    });
+    let benefit = so_that.map(|start| &text[start + "so that".len()..]);
+
+    Some(UserStory {
+        text: text.to_string(),
+        role: role.and_then(|role| non_empty(Some(role))),
+        capability: capability.and_then(|capability| non_empty(Some(capability))),
+        benefit: benefit.and_then(|benefit| non_empty(Some(benefit))),
+    })
+}
+
+/// 先頭の大文字キーワードを取り除く
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    text.strip_prefix(keyword)
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+}
+
+/// 単語として現れる大文字キーワードで前後に分割する
+fn find_keyword<'a>(text: &'a str, keyword: &str) -> Option<(&'a str, &'a str)> {
+    text.match_indices(keyword).find_map(|(start, _)| {
+        let end = start + keyword.len();
+        let before_ok = text[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        let after_ok = text[end..].chars().next().is_none_or(char::is_whitespace);
+        (before_ok && after_ok).then(|| (&text[..start], &text[end..]))
+    })
+}
+
+/// 前後の空白と句読点を取り除き、空なら `None` を返す
+fn non_empty(text: Option<&str>) -> Option<String> {
+    let text = text?.trim().trim_matches([',', '、']).trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Markdown 見出しを (レベル, テキスト) に分解する
+fn parse_heading(trimmed: &str) -> Option<(usize, &str)> {
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    let text = &trimmed[level..];
+
+    if level == 0 || !(text.is_empty() || text.starts_with(' ')) {
+        return None;
+    }
+
+    Some((level, text.trim()))
+}
+
+/// `Requirement 1` / `要件 1: タイトル` 形式の見出しを (番号, タイトル) に分解する
+fn parse_requirement_heading(text: &str) -> Option<(String, String)> {
+    let rest = if text.len() >= "requirement".len()
+        && text.is_char_boundary("requirement".len())
+        && text[.."requirement".len()].eq_ignore_ascii_case("requirement")
+    {
+        &text["requirement".len()..]
+    } else {
+        text.strip_prefix("要件")?
+    };
+
+    let rest = rest.trim_start();
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    if end == 0 {
+        return None;
+    }
+
+    let (number, title) = rest.split_at(end);
+    let title = title.trim_start_matches([':', '：']).trim();
+
+    Some((number.to_string(), title.to_string()))
+}
+
+/// `1. テキスト` 形式の番号付きリスト項目を (番号, テキスト) に分解する
+fn parse_ordered_item(trimmed: &str) -> Option<(u32, &str)> {
+    let (number, text) = trimmed.split_once(". ")?;
+    let number = number.parse::<u32>().ok()?;
+    Some((number, text.trim()))
+}
+
+/// 行頭の空白幅を返す（タブは 4 桁として数える）
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_requirements_english() {
+        let content = "# Requirements Document\n\n## Requirements\n\n### Requirement 1\n\n**User Story:** As a user, I want X.\n\n#### Acceptance Criteria\n\n1. WHEN a THEN the system SHALL b\n2. WHEN c THEN the system SHALL d\n\n### Requirement 2\n\n1. WHEN e THEN the system SHALL f\n\n## Glossary\n\n1. Not a criterion";
+
+        let requirements = parse_requirements(content);
+
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(requirements[0].id, "1");
+        assert_eq!(requirements[0].title, "");
+        assert_eq!(requirements[0].line, 5);
+        assert_eq!(requirements[0].criteria.len(), 2);
+        assert_eq!(requirements[0].criteria[1].id, "1.2");
+        assert_eq!(
+            requirements[0].criteria[1].text,
+            "WHEN c THEN the system SHALL d"
+        );
+        assert_eq!(requirements[1].criteria.len(), 1);
+        assert_eq!(requirements[1].criteria[0].id, "2.1");
+        assert!(requirements[1].user_story.is_none());
+    }
+
+    #[test]
+    fn test_parse_requirements_japanese() {
+        let content = "## 要件\n\n### 要件 1: Markdown ファイルの読み込み\n\n**ユーザーストーリー**: 開発者として、ファイルを読み込みたい。\n\n#### 受け入れ基準\n\n1. WHEN A THEN B\n\n### 要件 2: タスクリストの解析\n\n1. WHEN C THEN D\n2. WHEN E THEN F";
+
+        let requirements = parse_requirements(content);
+
+        assert_eq!(requirements.len(), 2);
+        assert_eq!(requirements[0].title, "Markdown ファイルの読み込み");
+        assert_eq!(
+            requirements[0].user_story.as_ref().unwrap().text,
+            "開発者として、ファイルを読み込みたい。"
+        );
+        assert_eq!(requirements[1].id, "2");
+        assert_eq!(requirements[1].criteria[1].id, "2.2");
+    }
+
+    #[test]
+    fn test_parse_requirements_skips_code_fences() {
+        let content = "### Requirement 1: Login\n\
+                       \n\
+                       1. WHEN a user logs in THEN the system SHALL greet them\n\
+                       \n\
+                       ```markdown\n\
+                       ### Requirement 9: Example\n\
+                       2. WHEN shown as an example THEN the system SHALL ignore it\n\
+                       ```\n\
+                       \n\
+                       ~~~\n\
+                       3. WHEN inside a tilde fence THEN the system SHALL ignore it\n\
+                       ~~~\n\
+                       2. WHEN a user logs out THEN the system SHALL say goodbye\n";
+
+        let requirements = parse_requirements(content);
+
+        assert_eq!(requirements.len(), 1);
+        let ids: Vec<&str> = requirements[0]
+            .criteria
+            .iter()
+            .map(|criterion| criterion.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["1.1", "1.2"]);
+        assert_eq!(requirements[0].criteria[1].line, 13);
+    }
+
+    #[test]
+    fn test_parse_requirements_file_not_exist() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let requirements =
+            parse_requirements_file(&temp_dir.path().join("requirements.md")).unwrap();
+
+        assert!(requirements.is_empty());
+    }
+
+    #[test]
+    fn test_parse_user_story_parts() {
+        let story = parse_user_story(
+            "**User Story:** As a team lead, I want to see progress, so that I can plan sprints",
+        )
+        .unwrap();
+
+        assert_eq!(story.role.as_deref(), Some("team lead"));
+        assert_eq!(story.capability.as_deref(), Some("to see progress"));
+        assert_eq!(story.benefit.as_deref(), Some("I can plan sprints"));
+        assert!(parse_user_story("Some other line").is_none());
+    }
+
+    #[test]
+    fn test_parse_ears_patterns() {
+        let event =
+            parse_ears("WHEN the user presses Enter THEN the system SHALL open the detail view");
+        assert_eq!(event.pattern, EarsPattern::EventDriven);
+        assert_eq!(event.condition.as_deref(), Some("the user presses Enter"));
+        assert_eq!(event.system.as_deref(), Some("the system"));
+        assert_eq!(event.response.as_deref(), Some("open the detail view"));
+
+        let unwanted = parse_ears("IF the file is missing, the system SHALL show an error");
+        assert_eq!(unwanted.pattern, EarsPattern::Unwanted);
+        assert_eq!(unwanted.condition.as_deref(), Some("the file is missing"));
+        assert_eq!(unwanted.system.as_deref(), Some("the system"));
+
+        let ubiquitous = parse_ears("THE system SHALL use UTF-8");
+        assert_eq!(ubiquitous.pattern, EarsPattern::Ubiquitous);
+        assert_eq!(ubiquitous.condition, None);
+        assert_eq!(ubiquitous.system.as_deref(), Some("THE system"));
+        assert_eq!(ubiquitous.response.as_deref(), Some("use UTF-8"));
+
+        let japanese = parse_ears("WHEN ファイルが空である THEN システムは空文字列を返す");
+        assert_eq!(japanese.pattern, EarsPattern::EventDriven);
+        assert_eq!(japanese.condition.as_deref(), Some("ファイルが空である"));
+        assert_eq!(japanese.system, None);
+        assert_eq!(
+            japanese.response.as_deref(),
+            Some("システムは空文字列を返す")
+        );
+    }
+}
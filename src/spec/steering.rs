@@ -0,0 +1,166 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+
+use crate::spec::metadata::front_matter_fields;
+
+/// ステアリングドキュメントをコンテキストに含める条件（フロントマターの `inclusion:`）
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inclusion {
+    /// 常に含める（フロントマターがない場合の既定）
+    Always,
+    /// `fileMatchPattern` に一致するファイルを扱うときだけ含める
+    FileMatch,
+    /// チャットで `#` 指定したときだけ含める
+    Manual,
+    /// 未知の値（記載されたまま表示する）
+    Unknown(String),
+}
+
+impl Inclusion {
+    pub fn label(&self) -> &str {
+        match self {
+            Inclusion::Always => "always",
+            Inclusion::FileMatch => "fileMatch",
+            Inclusion::Manual => "manual",
+            Inclusion::Unknown(value) => value,
+        }
+    }
+}
+
+/// `.kiro/steering` 配下のステアリングドキュメント
+#[derive(Debug, Clone, PartialEq)]
+pub struct SteeringDoc {
+    // ファイル名（`product.md` など）
+    pub name: String,
+    // ファイルのパス
+    pub path: PathBuf,
+    // コンテキストに含める条件
+    pub inclusion: Inclusion,
+    // `inclusion: fileMatch` の対象パターン
+    pub file_match_pattern: Option<String>,
+}
+
+/// フロントマターから含める条件と対象パターンを読み取る
+///
+/// # 引数
+/// * `content` - Markdown テキスト
+///
+/// # 戻り値
+/// * `(Inclusion, Option<String>)` - 含める条件と `fileMatchPattern`
+pub fn parse_inclusion(content: &str) -> (Inclusion, Option<String>) {
+    let mut inclusion = Inclusion::Always;
+    let mut pattern = None;
+
+    for (key, value) in front_matter_fields(content) {
+        match key.as_str() {
+            "inclusion" => {
+                inclusion = match value.to_lowercase().as_str() {
+                    "always" => Inclusion::Always,
+                    "filematch" => Inclusion::FileMatch,
+                    "manual" => Inclusion::Manual,
+                    _ => Inclusion::Unknown(value),
+                }
+            }
+            "fileMatchPattern" if !value.is_empty() => pattern = Some(value),
+            _ => {}
+        }
+    }
+
+    (inclusion, pattern)
+}
+
+/// `.kiro/steering` 配下のステアリングドキュメントを探索
+///
+/// # 引数
+/// * `project_root` - プロジェクトのルートディレクトリ
+///
+/// # 戻り値
+/// * `Ok(Vec<SteeringDoc>)` - ファイル名順のドキュメント（ディレクトリがなければ空）
+pub fn find_steering_docs(project_root: &Path) -> Result<Vec<SteeringDoc>> {
+    let steering_dir = project_root.join(".kiro").join("steering");
+    if !steering_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut docs = Vec::new();
+    for entry in fs::read_dir(&steering_dir)? {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+
+        // 読めないファイルも一覧には表示し、含める条件は既定値とする
+        let (inclusion, file_match_pattern) = fs::read_to_string(&path)
+            .map(|content| parse_inclusion(&content))
+            .unwrap_or((Inclusion::Always, None));
+        docs.push(SteeringDoc {
+            name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path,
+            inclusion,
+            file_match_pattern,
+        });
+    }
+
+    docs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(docs)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_inclusion() {
+        assert_eq!(parse_inclusion("# Product\n"), (Inclusion::Always, None));
+        assert_eq!(
+            parse_inclusion("---\ninclusion: fileMatch\nfileMatchPattern: \"src/**/*.rs\"\n---\n"),
+            (Inclusion::FileMatch, Some("src/**/*.rs".to_string()))
+        );
+        assert_eq!(
+            parse_inclusion("---\ninclusion: manual\n---\n").0,
+            Inclusion::Manual
+        );
+        assert_eq!(
+            parse_inclusion("---\ninclusion: sometimes\n---\n").0,
+            Inclusion::Unknown("sometimes".to_string())
+        );
+    }
+
+    #[test]
+    fn test_find_steering_docs() {
+        let temp_dir = TempDir::new().unwrap();
+        let steering_dir = temp_dir.path().join(".kiro").join("steering");
+        fs::create_dir_all(&steering_dir).unwrap();
+        fs::write(steering_dir.join("tech.md"), "# Tech").unwrap();
+        fs::write(
+            steering_dir.join("api-style.md"),
+            "---\ninclusion: manual\n---\n# API",
+        )
+        .unwrap();
+        fs::write(steering_dir.join("notes.txt"), "ignored").unwrap();
+
+        let docs = find_steering_docs(temp_dir.path()).unwrap();
+
+        let names: Vec<&str> = docs.iter().map(|doc| doc.name.as_str()).collect();
+        assert_eq!(names, vec!["api-style.md", "tech.md"]);
+        assert_eq!(docs[0].inclusion, Inclusion::Manual);
+        assert_eq!(docs[1].inclusion, Inclusion::Always);
+    }
+
+    #[test]
+    fn test_find_steering_docs_without_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        assert!(find_steering_docs(temp_dir.path()).unwrap().is_empty());
+    }
+}
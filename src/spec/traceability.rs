@@ -0,0 +1,211 @@
+use crate::spec::parser::{Task, flatten_tasks};
+use crate::spec::requirements::Requirement;
+
+/// 要件を参照しているタスク
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskRef {
+    // タスク番号（番号が無い場合はタイトル）
+    pub label: String,
+    // 完了状態
+    pub completed: bool,
+    // tasks.md 内の行番号
+    pub line: usize,
+}
+
+impl TaskRef {
+    fn from_task(task: &Task) -> Self {
+        Self {
+            label: task
+                .id
+                .as_ref()
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| task.title.clone()),
+            completed: task.is_completed(),
+            line: task.line,
+        }
+    }
+}
+
+/// 受け入れ基準ごとの参照タスク
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriterionTrace {
+    pub id: String,
+    pub tasks: Vec<TaskRef>,
+}
+
+/// 要件ごとの参照タスク（要件自体と配下の受け入れ基準への参照を合算）
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequirementTrace {
+    pub id: String,
+    pub title: String,
+    pub criteria: Vec<CriterionTrace>,
+    pub tasks: Vec<TaskRef>,
+}
+
+impl RequirementTrace {
+    /// 実装タスクが一つも無いか
+    pub fn is_unimplemented(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// 参照タスクのうち完了したものの数
+    pub fn completed_tasks(&self) -> usize {
+        self.tasks.iter().filter(|task| task.completed).count()
+    }
+
+    /// 完了タスクによるカバー率（0.0〜1.0）
+    pub fn coverage(&self) -> f64 {
+        if self.tasks.is_empty() {
+            0.0
+        } else {
+            self.completed_tasks() as f64 / self.tasks.len() as f64
+        }
+    }
+}
+
+/// 存在しない要件を指しているタスク参照
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingRef {
+    pub task: TaskRef,
+    pub reference: String,
+}
+
+/// tasks.md から requirements.md へのトレーサビリティマトリクス
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Traceability {
+    pub requirements: Vec<RequirementTrace>,
+    pub dangling: Vec<DanglingRef>,
+}
+
+impl Traceability {
+    /// 実装タスクが一つも無い要件
+    pub fn unimplemented(&self) -> Vec<&RequirementTrace> {
+        self.requirements
+            .iter()
+            .filter(|requirement| requirement.is_unimplemented())
+            .collect()
+    }
+}
+
+/// 要件一覧とタスクツリーからトレーサビリティマトリクスを構築する
+///
+/// # 引数
+/// * `requirements` - requirements.md から抽出した要件
+/// * `tasks` - tasks.md のタスクツリー
+///
+/// # 戻り値
+/// * `Traceability` - 要件ごとの参照タスクと未解決の参照
+pub fn build_traceability(requirements: &[Requirement], tasks: &[Task]) -> Traceability {
+    let mut traceability = Traceability {
+        requirements: requirements
+            .iter()
+            .map(|requirement| RequirementTrace {
+                id: requirement.id.clone(),
+                title: requirement.title.clone(),
+                criteria: requirement
+                    .criteria
+                    .iter()
+                    .map(|criterion| CriterionTrace {
+                        id: criterion.id.clone(),
+                        tasks: Vec::new(),
+                    })
+                    .collect(),
+                tasks: Vec::new(),
+            })
+            .collect(),
+        dangling: Vec::new(),
+    };
+
+    for task in flatten_tasks(tasks) {
+        for reference in task.requirement_refs() {
+            let task_ref = TaskRef::from_task(task);
+            let requirement_id = reference.split('.').next().unwrap_or_default();
+
+            let Some(requirement) = traceability
+                .requirements
+                .iter_mut()
+                .find(|requirement| requirement.id == requirement_id)
+            else {
+                traceability.dangling.push(DanglingRef {
+                    task: task_ref,
+                    reference,
+                });
+                continue;
+            };
+
+            if reference != requirement.id {
+                let Some(criterion) = requirement
+                    .criteria
+                    .iter_mut()
+                    .find(|criterion| criterion.id == reference)
+                else {
+                    traceability.dangling.push(DanglingRef {
+                        task: task_ref,
+                        reference,
+                    });
+                    continue;
+                };
+
+                if !criterion.tasks.contains(&task_ref) {
+                    criterion.tasks.push(task_ref.clone());
+                }
+            }
+
+            if !requirement.tasks.contains(&task_ref) {
+                requirement.tasks.push(task_ref);
+            }
+        }
+    }
+
+    traceability
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spec::parser::parse_tasks;
+    use crate::spec::requirements::parse_requirements;
+
+    const REQUIREMENTS: &str = "### Requirement 1\n\n1. WHEN a THEN b\n2. WHEN c THEN d\n\n### Requirement 2\n\n1. WHEN e THEN f\n\n### Requirement 3\n\n1. WHEN g THEN h";
+
+    #[test]
+    fn test_build_traceability() {
+        let requirements = parse_requirements(REQUIREMENTS);
+        let tasks = parse_tasks(
+            "- [x] 1. A\n  - _Requirements: 1.1, 1.2_\n- [ ] 2. B\n  - _Requirements: 1.2, 2_\n- [ ] 3. C\n  - _Requirements: 4.1, 2.5_",
+        );
+
+        let traceability = build_traceability(&requirements, &tasks);
+
+        let first = &traceability.requirements[0];
+        assert_eq!(first.tasks.len(), 2);
+        assert_eq!(first.completed_tasks(), 1);
+        assert_eq!(first.coverage(), 0.5);
+        assert_eq!(first.criteria[0].tasks.len(), 1);
+        assert_eq!(first.criteria[1].tasks.len(), 2);
+
+        let second = &traceability.requirements[1];
+        assert_eq!(second.tasks.len(), 1);
+        assert!(second.criteria[0].tasks.is_empty());
+
+        let unimplemented = traceability.unimplemented();
+        assert_eq!(unimplemented.len(), 1);
+        assert_eq!(unimplemented[0].id, "3");
+
+        let dangling: Vec<&str> = traceability
+            .dangling
+            .iter()
+            .map(|dangling| dangling.reference.as_str())
+            .collect();
+        assert_eq!(dangling, vec!["4.1", "2.5"]);
+        assert_eq!(traceability.dangling[0].task.label, "3");
+    }
+
+    #[test]
+    fn test_build_traceability_empty() {
+        let traceability = build_traceability(&[], &[]);
+
+        assert!(traceability.requirements.is_empty());
+        assert!(traceability.dangling.is_empty());
+    }
+}
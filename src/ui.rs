@@ -1,42 +1,80 @@
+use std::path::Path;
+use std::rc::Rc;
+
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Gauge, List, ListItem, Padding, Paragraph, Tabs},
+    widgets::{
+        Axis, Block, BorderType, Borders, Chart, Dataset, Gauge, GraphType, List, ListItem,
+        Padding, Paragraph, Tabs, Wrap,
+    },
 };
 
-use crate::app::{App, DetailTab, ViewMode};
-use crate::spec::reader::read_spec_content;
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::{App, DetailTab, SortKey, ViewMode};
+use crate::mermaid::expand_mermaid;
+use crate::spec::compare::TaskChange;
+use crate::spec::finder::{Phase, SpecSet};
+use crate::spec::hooks::HookStatus;
+use crate::spec::metadata::METADATA_KEYS;
+use crate::spec::outline::{Heading, has_children};
+use crate::spec::parser::{
+    ProgressMode, Task, TaskStatus, check_completion, check_task_ids, count_required_tasks,
+    flatten_tasks,
+};
+use crate::spec::requirements::Requirement;
+use crate::spec::steering::Inclusion;
+use crate::spec::traceability::build_traceability;
 
 // Define a palette based on Charm's aesthetics
 const COLOR_PRIMARY: Color = Color::Magenta;
 const COLOR_SECONDARY: Color = Color::Cyan;
 const COLOR_TEXT: Color = Color::White;
 const COLOR_SUBTEXT: Color = Color::DarkGray;
+const COLOR_WARNING: Color = Color::Yellow;
+const COLOR_ACTIVE: Color = Color::Green;
+
+// 詳細ビューの診断パネルに表示する最大行数
+const MAX_DIAGNOSTIC_ROWS: usize = 5;
+// リストビューのメタデータ列の最大幅
+const METADATA_COLUMN_WIDTH: usize = 12;
+// リストビューのフェーズ列の幅（最長の `in progress` に合わせる）
+const PHASE_COLUMN_WIDTH: usize = 11;
+// リストビューの最終変更列の幅（`11mo ago` など）
+const LAST_TOUCHED_COLUMN_WIDTH: usize = 9;
 
 pub fn render(app: &mut App, frame: &mut Frame) {
     match &app.view_mode {
         ViewMode::List => render_list_view(app, frame),
         ViewMode::Detail { spec_index } => render_detail_view(app, frame, *spec_index),
+        ViewMode::Steering => render_steering_view(app, frame),
+        ViewMode::SteeringDetail { index } => render_steering_detail_view(app, frame, *index),
+        ViewMode::Hooks => render_hooks_view(app, frame),
     }
 }
 
 /// タブ UI をレンダリングする
 ///
 /// # 引数
+/// * `tabs` - 表示するタブ
+/// * `titles` - タブの表示名
 /// * `active_tab` - 現在アクティブなタブ
 /// * `area` - レンダリング領域
 /// * `frame` - フレーム
-fn render_tabs(active_tab: &DetailTab, area: Rect, frame: &mut Frame) {
-    let tab_titles = vec!["Requirements", "Design", "Tasks"];
-    let selected_index = match active_tab {
-        DetailTab::Requirements => 0,
-        DetailTab::Design => 1,
-        DetailTab::Tasks => 2,
-    };
+fn render_tabs(
+    tabs: &[DetailTab],
+    titles: Vec<String>,
+    active_tab: &DetailTab,
+    area: Rect,
+    frame: &mut Frame,
+) {
+    let selected_index = tabs.iter().position(|tab| tab == active_tab).unwrap_or(0);
 
-    let tabs = Tabs::new(tab_titles)
+    let tabs = Tabs::new(titles)
         .select(selected_index)
         .style(Style::default().fg(COLOR_SUBTEXT))
         .highlight_style(
@@ -49,16 +87,8 @@ fn render_tabs(active_tab: &DetailTab, area: Rect, frame: &mut Frame) {
     frame.render_widget(tabs, area);
 }
 
-/// 詳細ビューをレンダリングする
-///
-/// # 引数
-/// * `app` - アプリケーション状態
-/// * `frame` - フレーム
-/// * `spec_index` - 表示する Spec のインデックス
-fn render_detail_view(app: &mut App, frame: &mut Frame, spec_index: usize) {
-    let area = frame.area();
-
-    // マージンを追加
+/// 画面の周囲に余白を取った描画領域（"floating window" 風の見た目にする）
+fn padded_area(area: Rect) -> Rect {
     let outer_layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -77,7 +107,111 @@ fn render_detail_view(app: &mut App, frame: &mut Frame, spec_index: usize) {
         ])
         .split(outer_layout[1]);
 
-    let content_area = horizontal_layout[1];
+    horizontal_layout[1]
+}
+
+/// ヘッダー（左にプロジェクトのルート、右にバージョン）を描画する
+///
+/// 一覧画面では Spec・ステアリング・フックの切り替えタブも表示する。
+fn render_header(app: &App, area: Rect, frame: &mut Frame) {
+    let version = env!("CARGO_PKG_VERSION");
+    let title = format!("[ KIRO RADAR - {} ]", version);
+    let title_width = title.len() as u16;
+
+    let view_tabs = match app.view_mode {
+        ViewMode::List => Some(0),
+        ViewMode::Steering => Some(1),
+        ViewMode::Hooks => Some(2),
+        _ => None,
+    };
+    let view_titles = [
+        "Specs".to_string(),
+        format!("Steering ({})", app.steering_docs.len()),
+        format!("Hooks ({})", app.hooks.len()),
+    ];
+    // 各タイトルの左右の余白と区切り（`|`）、右側の間隔を含めた幅
+    let tabs_width = if view_tabs.is_some() {
+        view_titles
+            .iter()
+            .map(|title| title.width() + 3)
+            .sum::<usize>()
+    } else {
+        0
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(tabs_width as u16),
+            Constraint::Length(title_width),
+        ])
+        .split(area);
+
+    // 幅が足りない場合は末尾のディレクトリ名を残して先頭を省略する
+    let root = display_root(&app.project_root);
+    let max = (chunks[0].width as usize).saturating_sub(1);
+    let count = root.chars().count();
+    let root = if count > max {
+        let tail: String = root.chars().skip(count - max + 1).collect();
+        format!("…{}", tail)
+    } else {
+        root
+    };
+    // 探索と設定ファイルの誤りはどのビューでも見えるようヘッダーに表示する
+    let mut spans = vec![Span::styled(root, Style::default().fg(COLOR_SUBTEXT))];
+    let errors: Vec<&String> = app.scan_errors.iter().chain(&app.config_errors).collect();
+    if let Some(error) = errors.first() {
+        let more = match errors.len() {
+            1 => String::new(),
+            count => format!(" (+{} more)", count - 1),
+        };
+        spans.push(Span::styled(
+            format!("  ⚠ {}{}", error.lines().next().unwrap_or_default(), more),
+            Style::default().fg(COLOR_WARNING),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), chunks[0]);
+
+    if let Some(selected) = view_tabs {
+        let tabs = Tabs::new(view_titles)
+            .select(selected)
+            .style(Style::default().fg(COLOR_SUBTEXT))
+            .highlight_style(
+                Style::default()
+                    .fg(COLOR_PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .divider("|");
+        frame.render_widget(tabs, chunks[1]);
+    }
+
+    let header = Paragraph::new(title).alignment(Alignment::Right).style(
+        Style::default()
+            .fg(COLOR_SECONDARY)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(header, chunks[2]);
+}
+
+/// ホームディレクトリを `~` に置き換えたルートの表示名
+fn display_root(root: &Path) -> String {
+    if let Some(home) = std::env::var_os("HOME")
+        && let Ok(relative) = root.strip_prefix(&home)
+    {
+        return Path::new("~").join(relative).display().to_string();
+    }
+    root.display().to_string()
+}
+
+/// 詳細ビューをレンダリングする
+///
+/// # 引数
+/// * `app` - アプリケーション状態
+/// * `frame` - フレーム
+/// * `spec_index` - 表示する Spec のインデックス
+fn render_detail_view(app: &mut App, frame: &mut Frame, spec_index: usize) {
+    let content_area = padded_area(frame.area());
 
     // メインレイアウト
     let chunks = Layout::default()
@@ -91,82 +225,340 @@ fn render_detail_view(app: &mut App, frame: &mut Frame, spec_index: usize) {
         .split(content_area);
 
     // ヘッダー
-    let version = env!("CARGO_PKG_VERSION");
-    let header = Paragraph::new(format!("[ KIRO RADAR - {} ]", version))
-        .alignment(Alignment::Right)
-        .style(
-            Style::default()
-                .fg(COLOR_SECONDARY)
-                .add_modifier(Modifier::BOLD),
-        );
-    frame.render_widget(header, chunks[0]);
+    render_header(app, chunks[0], frame);
 
     // Spec 名とタブ
-    let spec_name = app
-        .spec_sets
-        .get(spec_index)
-        .map(|s| s.name.as_str())
-        .unwrap_or("Unknown");
+    let spec_name = match app.spec_sets.get(spec_index) {
+        Some(spec) if !spec.package.is_empty() => format!("{} › {}", spec.package, spec.name),
+        Some(spec) => spec.name.clone(),
+        None => "Unknown".to_string(),
+    };
+
+    // タブは Spec ごとに異なる（追加の Markdown があればその分増える）
+    let tabs = DetailTab::all(
+        app.spec_sets
+            .get(spec_index)
+            .map_or(0, |spec| spec.documents.len()),
+    );
+    let titles: Vec<String> = match app.spec_sets.get(spec_index) {
+        Some(spec) => tabs.iter().map(|tab| tab.title(spec)).collect(),
+        None => Vec::new(),
+    };
+    // 各タイトルの左右の余白と区切り（` | `）を含めた幅
+    let tabs_width = titles.iter().map(|title| title.width() + 2).sum::<usize>()
+        + titles.len().saturating_sub(1) * 3
+        + 4;
 
     // Spec 名とタブを横に並べる
     let header_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([
-            Constraint::Min(0),     // Spec name
-            Constraint::Length(40), // Tabs
+            Constraint::Min(20),                   // Spec name
+            Constraint::Length(tabs_width as u16), // Tabs
         ])
         .split(chunks[1]);
 
-    let spec_header = Paragraph::new(format!("Spec: {}", spec_name)).style(
+    let mut header_spans = vec![Span::styled(
+        format!("Spec: {}", spec_name),
         Style::default()
             .fg(COLOR_PRIMARY)
             .add_modifier(Modifier::BOLD),
-    );
-    frame.render_widget(spec_header, header_chunks[0]);
+    )];
+    // git の履歴があれば最後のコミットの作者・日付とコミット数を添える
+    if let Some(git) = app
+        .spec_sets
+        .get(spec_index)
+        .and_then(|spec| spec.git.as_ref())
+    {
+        header_spans.push(Span::styled(
+            format!(
+                "  {} · {} · {} commit{} · {}",
+                git.author,
+                git.date,
+                git.commit_count,
+                if git.commit_count == 1 { "" } else { "s" },
+                git.last_commit
+            ),
+            Style::default().fg(COLOR_SUBTEXT),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(header_spans)), header_chunks[0]);
 
     // タブ UI
-    render_tabs(&app.active_tab, header_chunks[1], frame);
+    render_tabs(&tabs, titles, &app.active_tab, header_chunks[1], frame);
+
+    // 読み込みエラーがあればコンテンツ領域の上にエラーパネルを表示
+    let error_count = app
+        .spec_sets
+        .get(spec_index)
+        .map_or(0, |spec| spec.errors.len());
+    let content_area = if error_count == 0 {
+        chunks[2]
+    } else {
+        let panel_height = error_count.min(MAX_DIAGNOSTIC_ROWS) as u16 + 2;
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(panel_height), // Errors
+                Constraint::Min(0),               // Content
+            ])
+            .split(chunks[2]);
+        render_errors(&app.spec_sets[spec_index], rows[0], frame);
+        rows[1]
+    };
+
+    // 診断があればコンテンツ領域の下に診断パネルを表示
+    let diagnostic_count = app
+        .spec_sets
+        .get(spec_index)
+        .map_or(0, |spec| spec.diagnostics.len());
+    let content_area = if diagnostic_count == 0 {
+        content_area
+    } else {
+        let panel_height = diagnostic_count.min(MAX_DIAGNOSTIC_ROWS) as u16 + 2;
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(0),               // Content
+                Constraint::Length(panel_height), // Diagnostics
+            ])
+            .split(content_area);
+        render_diagnostics(&app.spec_sets[spec_index], rows[1], frame);
+        rows[0]
+    };
 
     // コンテンツ領域
-    let spec_set = app.spec_sets.get(spec_index);
-    let content_text = if let Some(spec) = spec_set {
-        let spec_content = read_spec_content(spec);
-        match app.active_tab {
-            DetailTab::Requirements => spec_content.requirements,
-            DetailTab::Design => spec_content.design,
-            DetailTab::Tasks => spec_content.tasks,
-        }
+    if app.navigates_requirements() {
+        render_requirements_view(app, content_area, frame);
+    } else if app.active_tab == DetailTab::History {
+        render_history(app, content_area, frame);
+    } else {
+        let title = app
+            .spec_sets
+            .get(spec_index)
+            .map(|spec| app.active_tab.file_name(spec))
+            .unwrap_or_default();
+        let lines = current_lines(app);
+        render_document(app, &lines, title, content_area, frame);
+    }
+
+    // フッター
+    let footer_text = if app.outline_focus {
+        "[ ↑/k ↓/j: Select, Enter: Jump, Space: Fold, o/Esc: Document, q: Quit ]"
+    } else if app.navigates_requirements() {
+        "[ Tab: Switch, ↑/k: Prev, ↓/j: Next, Esc: Back, q: Quit ]"
+    } else if app.active_tab == DetailTab::History {
+        "[ Tab: Switch, Esc: Back, q: Quit ]"
+    } else if app.outline_available() {
+        "[ Tab: Switch, ↑/k: Up, ↓/j: Down, o: Outline, m: Mermaid, Esc: Back, q: Quit ]"
+    } else {
+        "[ Tab: Switch, ↑/k: Up, ↓/j: Down, m: Mermaid, Esc: Back, q: Quit ]"
+    };
+    let footer = Paragraph::new(footer_text)
+        .alignment(Alignment::Right)
+        .style(
+            Style::default()
+                .fg(COLOR_SUBTEXT)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// tasks.md の git 履歴から再構成した進捗の推移（バーンアップチャート）をレンダリングする
+///
+/// 各コミット時点の必須タスクの総数（スコープ）と完了数を折れ線で描き、
+/// 枠の下部に期間中の完了数と 1 週間あたりの完了数（ベロシティ）を表示する。
+fn render_history(app: &App, area: Rect, frame: &mut Frame) {
+    let history = app.current_progress_history();
+    let block = Block::default()
+        .title(format!(
+            "Burn-up · tasks.md history ({} commits)",
+            history.len()
+        ))
+        .title_style(Style::default().fg(COLOR_SECONDARY))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(COLOR_SUBTEXT))
+        .padding(Padding::horizontal(1));
+
+    let (Some(first), Some(last)) = (history.first(), history.last()) else {
+        let message = Paragraph::new("No git history for tasks.md")
+            .style(Style::default().fg(COLOR_SUBTEXT))
+            .block(block);
+        frame.render_widget(message, area);
+        return;
+    };
+
+    let mut scope = Vec::with_capacity(history.len());
+    let mut done = Vec::with_capacity(history.len());
+    for point in history {
+        let (total, completed) = count_required_tasks(&point.tasks, app.progress_mode);
+        scope.push((point.timestamp as f64, total as f64));
+        done.push((point.timestamp as f64, completed as f64));
+    }
+    let max_tasks = scope.iter().map(|(_, total)| *total).fold(1.0, f64::max);
+    let start = first.timestamp as f64;
+    // コミットが 1 つだけでも線が見えるよう、最低 1 日の幅を取る
+    let end = (last.timestamp as f64).max(start + 86400.0);
+
+    // 期間中の完了数とベロシティ
+    let completed_delta = done.last().map_or(0.0, |(_, y)| *y) - done[0].1;
+    let days = (end - start) / 86400.0;
+    let remaining = scope.last().map_or(0.0, |(_, y)| *y) - done.last().map_or(0.0, |(_, y)| *y);
+    let summary = format!(
+        " {:+} done in {:.0} days · {:.1} tasks/week · {} remaining ",
+        completed_delta,
+        days,
+        completed_delta / days * 7.0,
+        remaining
+    );
+
+    let datasets = vec![
+        Dataset::default()
+            .name("scope")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(COLOR_SUBTEXT))
+            .data(&scope),
+        Dataset::default()
+            .name("done")
+            .marker(symbols::Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(COLOR_ACTIVE))
+            .data(&done),
+    ];
+    let chart = Chart::new(datasets)
+        .block(block.title_bottom(Line::styled(summary, Style::default().fg(COLOR_TEXT))))
+        .x_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_SUBTEXT))
+                .bounds([start, end])
+                .labels([first.date.clone(), last.date.clone()]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(Style::default().fg(COLOR_SUBTEXT))
+                .bounds([0.0, max_tasks])
+                .labels(["0".to_string(), format!("{}", max_tasks)]),
+        );
+    frame.render_widget(chart, area);
+}
+
+/// Spec の読み込みエラーのパネルをレンダリングする
+fn render_errors(spec: &SpecSet, area: Rect, frame: &mut Frame) {
+    let lines: Vec<Line> = spec
+        .errors
+        .iter()
+        .map(|error| Line::styled(error.clone(), Style::default().fg(COLOR_WARNING)))
+        .collect();
+
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!("⚠ read errors ({})", spec.errors.len()))
+            .title_style(
+                Style::default()
+                    .fg(COLOR_WARNING)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_WARNING))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(panel, area);
+}
+
+/// tasks.md の診断パネルをレンダリングする
+fn render_diagnostics(spec: &SpecSet, area: Rect, frame: &mut Frame) {
+    let lines: Vec<Line> = spec
+        .diagnostics
+        .iter()
+        .map(|diagnostic| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{}:{} ", diagnostic.line, diagnostic.column),
+                    Style::default().fg(COLOR_SUBTEXT),
+                ),
+                Span::styled(
+                    diagnostic.message.clone(),
+                    Style::default().fg(COLOR_WARNING),
+                ),
+                Span::styled("  → ", Style::default().fg(COLOR_SUBTEXT)),
+                Span::styled(
+                    diagnostic.suggestion.clone(),
+                    Style::default().fg(COLOR_ACTIVE),
+                ),
+            ])
+        })
+        .collect();
+
+    let panel = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(
+                "⚠ tasks.md diagnostics ({})",
+                spec.diagnostics.len()
+            ))
+            .title_style(Style::default().fg(COLOR_WARNING))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_SUBTEXT))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(panel, area);
+}
+
+/// ドキュメントの行をスクロール可能な形でレンダリングする
+///
+/// 詳細ビューのタブとステアリングドキュメントの表示で共通に使う。
+///
+/// # 引数
+/// * `app` - アプリケーション状態（スクロール位置とアウトライン）
+/// * `lines` - 表示する行
+/// * `title` - 枠に表示するファイル名
+/// * `area` - レンダリング領域
+/// * `frame` - フレーム
+fn render_document(
+    app: &mut App,
+    lines: &[Line<'static>],
+    title: String,
+    area: Rect,
+    frame: &mut Frame,
+) {
+    // 見出しがあればアウトラインサイドバーを表示
+    let headings = app.outline();
+    let area = if headings.is_empty() {
+        area
     } else {
-        None
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(32), // Outline
+                Constraint::Min(0),     // Document
+            ])
+            .split(area);
+        render_outline(app, &headings, columns[0], frame);
+        columns[1]
     };
 
-    let display_text = content_text.unwrap_or_else(|| "File not found".to_string());
-    let lines: Vec<&str> = display_text.lines().collect();
     let total_lines = lines.len();
 
     // 表示可能な行数を計算
-    let content_height = chunks[2].height.saturating_sub(2) as usize; // ボーダー分を引く
+    let content_height = area.height.saturating_sub(2) as usize; // ボーダー分を引く
     let max_scroll = total_lines.saturating_sub(content_height);
 
     // スクロール位置を調整
     let scroll_pos = app.detail_scroll.min(max_scroll);
 
-    // 表示する行を取得
+    // 表示する行だけを複製する
     let visible_lines: Vec<Line> = lines
         .iter()
         .skip(scroll_pos)
         .take(content_height)
-        .map(|&line| Line::from(line))
+        .cloned()
         .collect();
 
-    let tab_name = match app.active_tab {
-        DetailTab::Requirements => "requirements.md",
-        DetailTab::Design => "design.md",
-        DetailTab::Tasks => "tasks.md",
-    };
-
     let content_block = Block::default()
-        .title(tab_name)
+        .title(title)
         .title_style(Style::default().fg(COLOR_SECONDARY))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
@@ -174,99 +566,904 @@ fn render_detail_view(app: &mut App, frame: &mut Frame, spec_index: usize) {
         .padding(Padding::horizontal(1));
 
     let content_paragraph = Paragraph::new(visible_lines).block(content_block);
-    frame.render_widget(content_paragraph, chunks[2]);
+    frame.render_widget(content_paragraph, area);
+}
 
-    // フッター
-    let footer = Paragraph::new("[ Tab: Switch, ↑/k: Up, ↓/j: Down, Esc: Back, q: Quit ]")
-        .alignment(Alignment::Right)
-        .style(
+/// ステアリングドキュメントの一覧をレンダリングする
+fn render_steering_view(app: &mut App, frame: &mut Frame) {
+    let content_area = padded_area(frame.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Min(0),    // List
+            Constraint::Length(1), // Footer
+        ])
+        .split(content_area);
+
+    render_header(app, chunks[0], frame);
+
+    let name_width = app
+        .steering_docs
+        .iter()
+        .map(|doc| doc.name.width())
+        .max()
+        .unwrap_or(0)
+        .max(20);
+    let items: Vec<ListItem> = app
+        .steering_docs
+        .iter()
+        .map(|doc| {
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<name_width$}", doc.name, name_width = name_width),
+                    Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("  {:<9}", doc.inclusion.label()),
+                    Style::default().fg(inclusion_color(&doc.inclusion)),
+                ),
+            ];
+            if let Some(pattern) = &doc.file_match_pattern {
+                spans.push(Span::styled(
+                    format!("  {}", pattern),
+                    Style::default().fg(COLOR_SUBTEXT),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Steering · .kiro/steering ({})",
+                    app.steering_docs.len()
+                ))
+                .title_style(Style::default().fg(COLOR_PRIMARY))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(COLOR_SUBTEXT))
+                .padding(Padding::new(1, 1, 1, 1)),
+        )
+        .highlight_style(
             Style::default()
-                .fg(COLOR_SUBTEXT)
+                .bg(COLOR_PRIMARY)
                 .add_modifier(Modifier::BOLD),
         );
-    frame.render_widget(footer, chunks[3]);
+    frame.render_stateful_widget(list, chunks[1], &mut app.steering_state);
+
+    let footer =
+        Paragraph::new("[ ↑↓/jk -> navigate ] [ Enter -> open ] [ Tab -> hooks ] [ q  -> quit ]")
+            .alignment(Alignment::Right)
+            .style(
+                Style::default()
+                    .fg(COLOR_SUBTEXT)
+                    .add_modifier(Modifier::BOLD),
+            );
+    frame.render_widget(footer, chunks[2]);
 }
 
-/// リストビューをレンダリングする
-fn render_list_view(app: &mut App, frame: &mut Frame) {
-    let area = frame.area();
+/// ステアリングドキュメントを詳細ビューと同じビューアでレンダリングする
+fn render_steering_detail_view(app: &mut App, frame: &mut Frame, index: usize) {
+    let content_area = padded_area(frame.area());
 
-    // Add margins to create a "floating window" feel
-    let outer_layout = Layout::default()
+    let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(1), // Top margin
+            Constraint::Length(1), // Header
+            Constraint::Length(1), // Document name + inclusion
             Constraint::Min(0),    // Content
-            Constraint::Length(1), // Bottom margin
+            Constraint::Length(1), // Footer
         ])
-        .split(area);
+        .split(content_area);
 
-    let horizontal_layout = Layout::default()
-        .direction(Direction::Horizontal)
+    render_header(app, chunks[0], frame);
+
+    let Some(doc) = app.steering_docs.get(index).cloned() else {
+        return;
+    };
+    let mut spans = vec![
+        Span::styled(
+            format!("Steering: {}", doc.name),
+            Style::default()
+                .fg(COLOR_PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  inclusion: ", Style::default().fg(COLOR_SUBTEXT)),
+        Span::styled(
+            doc.inclusion.label().to_string(),
+            Style::default().fg(inclusion_color(&doc.inclusion)),
+        ),
+    ];
+    if let Some(pattern) = &doc.file_match_pattern {
+        spans.push(Span::styled(
+            format!(" ({})", pattern),
+            Style::default().fg(COLOR_SUBTEXT),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), chunks[1]);
+
+    let lines = current_lines(app);
+    render_document(app, &lines, doc.name.clone(), chunks[2], frame);
+
+    let footer_text = if app.outline_focus {
+        "[ ↑/k ↓/j: Select, Enter: Jump, Space: Fold, o/Esc: Document, q: Quit ]"
+    } else if app.outline_available() && !app.outline().is_empty() {
+        "[ ↑/k: Up, ↓/j: Down, o: Outline, m: Mermaid, Esc: Back, q: Quit ]"
+    } else {
+        "[ ↑/k: Up, ↓/j: Down, m: Mermaid, Esc: Back, q: Quit ]"
+    };
+    let footer = Paragraph::new(footer_text)
+        .alignment(Alignment::Right)
+        .style(
+            Style::default()
+                .fg(COLOR_SUBTEXT)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// 表示中のステアリングドキュメントの行
+fn steering_lines(app: &App) -> Vec<Line<'static>> {
+    document_lines(
+        app.document_text().as_deref().unwrap_or("File not found"),
+        app.show_mermaid_source,
+    )
+}
+
+/// 含める条件の表示色
+fn inclusion_color(inclusion: &Inclusion) -> Color {
+    match inclusion {
+        Inclusion::Always => COLOR_ACTIVE,
+        Inclusion::FileMatch => COLOR_SECONDARY,
+        Inclusion::Manual => COLOR_WARNING,
+        Inclusion::Unknown(_) => COLOR_SUBTEXT,
+    }
+}
+
+/// エージェントフックの一覧と選択中のフックの詳細をレンダリングする
+///
+/// 各行に状態（有効 / 無効 / 不正）、きっかけ、ファイルパターンを表示し、
+/// 下部のパネルに説明・プロンプト・検出された問題を表示する。
+fn render_hooks_view(app: &mut App, frame: &mut Frame) {
+    let content_area = padded_area(frame.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(2), // Left margin
-            Constraint::Min(0),    // Content
-            Constraint::Length(2), // Right margin
+            Constraint::Length(1),  // Header
+            Constraint::Min(0),     // List
+            Constraint::Length(10), // Selected hook
+            Constraint::Length(1),  // Footer
         ])
-        .split(outer_layout[1]);
+        .split(content_area);
 
-    let content_area = horizontal_layout[1];
+    render_header(app, chunks[0], frame);
 
-    // Calculate overall progress
-    let total_tasks: usize = app
-        .spec_sets
+    let name_width = app
+        .hooks
         .iter()
-        .map(|s| s.total_tasks.unwrap_or(0))
-        .sum();
-    let completed_tasks: usize = app
-        .spec_sets
+        .map(|hook| hook.name.width())
+        .max()
+        .unwrap_or(0)
+        .max(20);
+    let items: Vec<ListItem> = app
+        .hooks
         .iter()
-        .map(|s| s.completed_tasks.unwrap_or(0))
-        .sum();
+        .map(|hook| {
+            let status = hook.status();
+            let marker = match status {
+                HookStatus::Enabled => "●",
+                HookStatus::Disabled => "○",
+                HookStatus::Invalid => "⚠",
+            };
+            let trigger = hook
+                .trigger
+                .as_ref()
+                .map(|trigger| trigger.label())
+                .unwrap_or("-");
+            let name_style = if status == HookStatus::Disabled {
+                Style::default().fg(COLOR_SUBTEXT)
+            } else {
+                Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD)
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} {:<8} ", marker, status.label()),
+                    Style::default().fg(hook_status_color(status)),
+                ),
+                Span::styled(
+                    format!("{:<name_width$}", hook.name, name_width = name_width),
+                    name_style,
+                ),
+                Span::styled(
+                    format!("  {:<13}", trigger),
+                    Style::default().fg(COLOR_SECONDARY),
+                ),
+            ];
+            if !hook.patterns.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", hook.patterns.join(", ")),
+                    Style::default().fg(COLOR_SUBTEXT),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let enabled = app
+        .hooks
+        .iter()
+        .filter(|hook| hook.status() == HookStatus::Enabled)
+        .count();
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(
+                    "Hooks · .kiro/hooks ({} of {} enabled)",
+                    enabled,
+                    app.hooks.len()
+                ))
+                .title_style(Style::default().fg(COLOR_PRIMARY))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(COLOR_SUBTEXT))
+                .padding(Padding::new(1, 1, 1, 1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(COLOR_PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_stateful_widget(list, chunks[1], &mut app.hooks_state);
+
+    let mut lines = Vec::new();
+    if let Some(hook) = app.selected_hook() {
+        if let Some(description) = &hook.description {
+            lines.push(Line::from(Span::styled(
+                description.clone(),
+                Style::default().fg(COLOR_TEXT),
+            )));
+        }
+        if let Some(action) = &hook.action {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", action), Style::default().fg(COLOR_SUBTEXT)),
+                Span::styled(
+                    hook.prompt.clone().unwrap_or_default(),
+                    Style::default().fg(COLOR_TEXT),
+                ),
+            ]));
+        }
+        for problem in &hook.problems {
+            lines.push(Line::from(Span::styled(
+                format!("⚠ {}", problem),
+                Style::default().fg(COLOR_WARNING),
+            )));
+        }
+    }
+    let title = app
+        .selected_hook()
+        .map(|hook| hook.file_name.clone())
+        .unwrap_or_else(|| "No hooks".to_string());
+    let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(title)
+            .title_style(Style::default().fg(COLOR_SECONDARY))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_SUBTEXT))
+            .padding(Padding::horizontal(1)),
+    );
+    frame.render_widget(detail, chunks[2]);
+
+    let footer = Paragraph::new("[ ↑↓/jk -> navigate ] [ Tab -> specs ] [ q  -> quit ]")
+        .alignment(Alignment::Right)
+        .style(
+            Style::default()
+                .fg(COLOR_SUBTEXT)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(footer, chunks[3]);
+}
+
+/// 比較元からのタスクの変化の表示色
+fn change_color(change: TaskChange) -> Color {
+    match change {
+        TaskChange::Added => COLOR_SECONDARY,
+        TaskChange::Completed => COLOR_ACTIVE,
+        TaskChange::Reopened => COLOR_WARNING,
+        TaskChange::Started => COLOR_PRIMARY,
+        TaskChange::Paused => COLOR_SUBTEXT,
+    }
+}
+
+/// フックの状態の表示色
+fn hook_status_color(status: HookStatus) -> Color {
+    match status {
+        HookStatus::Enabled => COLOR_ACTIVE,
+        HookStatus::Disabled => COLOR_SUBTEXT,
+        HookStatus::Invalid => COLOR_WARNING,
+    }
+}
+
+/// 見出しのアウトラインサイドバーをレンダリングする
+///
+/// 各見出しの左にセクションの行数を表示し、子見出しを持つ見出しには
+/// 折りたたみ状態（▾ / ▸）を表示する。
+fn render_outline(app: &mut App, headings: &[Heading], area: Rect, frame: &mut Frame) {
+    let items: Vec<ListItem> = app
+        .visible_outline(headings)
+        .into_iter()
+        .map(|index| {
+            let heading = &headings[index];
+            let marker = if !has_children(headings, index) {
+                "  "
+            } else if app.collapsed_sections.contains(&heading.line) {
+                "▸ "
+            } else {
+                "▾ "
+            };
+            let title_style = if heading.level <= 2 {
+                Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(COLOR_TEXT)
+            };
 
-    let progress_ratio = if total_tasks > 0 {
-        completed_tasks as f64 / total_tasks as f64
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:>4} ", heading.section_lines),
+                    Style::default().fg(COLOR_SUBTEXT),
+                ),
+                Span::raw("  ".repeat(heading.level.saturating_sub(1))),
+                Span::styled(marker, Style::default().fg(COLOR_SECONDARY)),
+                Span::styled(heading.title.clone(), title_style),
+            ]))
+        })
+        .collect();
+
+    let border_color = if app.outline_focus {
+        COLOR_SECONDARY
     } else {
-        0.0
+        COLOR_SUBTEXT
+    };
+    let highlight_style = if app.outline_focus {
+        Style::default()
+            .bg(COLOR_PRIMARY)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Outline")
+                .title_style(Style::default().fg(COLOR_SECONDARY))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(border_color)),
+        )
+        .highlight_style(highlight_style);
+
+    frame.render_stateful_widget(list, area, &mut app.outline_state);
+}
+
+/// Requirements タブを要件リストと選択中の要件の詳細としてレンダリングする
+fn render_requirements_view(app: &mut App, area: Rect, frame: &mut Frame) {
+    let Some(spec) = app.current_spec() else {
+        return;
     };
 
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let items: Vec<ListItem> = spec
+        .requirement_list
+        .iter()
+        .map(|requirement| {
+            let story = if requirement.user_story.is_some() {
+                "story ✓"
+            } else {
+                "story ✗"
+            };
+            ListItem::new(vec![
+                Line::from(Span::styled(
+                    requirement_heading(requirement),
+                    Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+                )),
+                Line::styled(
+                    format!("  {} · {} criteria", story, requirement.criteria.len()),
+                    Style::default().fg(COLOR_SUBTEXT),
+                ),
+            ])
+        })
+        .collect();
+
+    let selected = app
+        .requirement_state
+        .selected()
+        .and_then(|index| spec.requirement_list.get(index));
+    let detail = selected.map(requirement_detail_lines).unwrap_or_default();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!("requirements.md ({})", spec.requirement_list.len()))
+                .title_style(Style::default().fg(COLOR_SECONDARY))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(COLOR_SUBTEXT))
+                .padding(Padding::horizontal(1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(COLOR_PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let detail_paragraph = Paragraph::new(detail).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title("Details")
+            .title_style(Style::default().fg(COLOR_SECONDARY))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(COLOR_SUBTEXT))
+            .padding(Padding::horizontal(1)),
+    );
+
+    frame.render_stateful_widget(list, columns[0], &mut app.requirement_state);
+    frame.render_widget(detail_paragraph, columns[1]);
+}
+
+/// 要件の見出し（タイトルがあれば併記）
+fn requirement_heading(requirement: &Requirement) -> String {
+    if requirement.title.is_empty() {
+        format!("Requirement {}", requirement.id)
+    } else {
+        format!("Requirement {}: {}", requirement.id, requirement.title)
+    }
+}
+
+/// 選択中の要件のユーザーストーリーと受け入れ基準の行を構築する
+fn requirement_detail_lines(requirement: &Requirement) -> Vec<Line<'static>> {
+    let label_style = Style::default()
+        .fg(COLOR_SECONDARY)
+        .add_modifier(Modifier::BOLD);
+    let keyword_style = Style::default()
+        .fg(COLOR_PRIMARY)
+        .add_modifier(Modifier::BOLD);
+    let text_style = Style::default().fg(COLOR_TEXT);
+
+    let mut lines = vec![
+        Line::styled(
+            requirement_heading(requirement),
+            Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+        Line::styled("User Story", label_style),
+    ];
+
+    match &requirement.user_story {
+        Some(story) => {
+            lines.push(Line::styled(format!("  {}", story.text), text_style));
+            for (label, value) in [
+                ("role", &story.role),
+                ("want", &story.capability),
+                ("benefit", &story.benefit),
+            ] {
+                if let Some(value) = value {
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("  {:<8}", label),
+                            Style::default().fg(COLOR_SUBTEXT),
+                        ),
+                        Span::styled(value.clone(), text_style),
+                    ]));
+                }
+            }
+        }
+        None => lines.push(Line::styled("  (none)", Style::default().fg(COLOR_WARNING))),
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        format!("Acceptance Criteria ({})", requirement.criteria.len()),
+        label_style,
+    ));
+
+    for criterion in &requirement.criteria {
+        let ears = &criterion.ears;
+        let mut spans = vec![Span::styled(
+            format!("  {:<6}", criterion.id),
+            Style::default().fg(COLOR_PRIMARY),
+        )];
+        if let Some(condition) = &ears.condition {
+            spans.push(Span::styled(
+                format!("{} ", ears.pattern.keyword()),
+                keyword_style,
+            ));
+            spans.push(Span::styled(format!("{} ", condition), text_style));
+        }
+        if let Some(system) = &ears.system {
+            spans.push(Span::styled(
+                format!("{} ", system),
+                Style::default().fg(COLOR_SUBTEXT),
+            ));
+            spans.push(Span::styled("SHALL ", keyword_style));
+        } else if ears.condition.is_some() {
+            spans.push(Span::styled("THEN ", keyword_style));
+        }
+        spans.push(Span::styled(
+            ears.response
+                .clone()
+                .unwrap_or_else(|| criterion.text.clone()),
+            text_style,
+        ));
+        lines.push(Line::from(spans));
+    }
+
+    lines
+}
+
+/// 表示中のドキュメントの行（`App` にキャッシュした行を再利用する）
+fn current_lines(app: &App) -> Rc<Vec<Line<'static>>> {
+    app.rendered_lines(|app| match app.current_spec() {
+        Some(spec) => detail_lines(app, spec),
+        None if app.current_steering_doc().is_some() => steering_lines(app),
+        None => vec![Line::from("File not found")],
+    })
+}
+
+/// 詳細ビューに表示する行を構築する
+///
+/// Tasks / Traceability タブは解析結果から行を生成し、
+/// それ以外のタブは表示中のファイルの内容を行に分割する。
+fn detail_lines(app: &App, spec: &SpecSet) -> Vec<Line<'static>> {
+    match app.active_tab {
+        DetailTab::Tasks if spec.tasks.is_some() => return task_lines(spec),
+        DetailTab::Traceability => return traceability_lines(spec),
+        // グラフで描画するためスクロールする行はない
+        DetailTab::History => return Vec::new(),
+        _ => {}
+    }
+
+    document_lines(
+        app.document_text().as_deref().unwrap_or("File not found"),
+        app.show_mermaid_source,
+    )
+}
+
+/// Markdown テキストを表示行に変換する（Mermaid 図は罫線で描画する）
+fn document_lines(content_text: &str, show_mermaid_source: bool) -> Vec<Line<'static>> {
+    if show_mermaid_source {
+        return content_text
+            .lines()
+            .map(|line| Line::from(line.to_string()))
+            .collect();
+    }
+
+    // Mermaid 図は罫線で描画して色を分ける
+    expand_mermaid(content_text)
+        .into_iter()
+        .map(|line| {
+            if line.diagram {
+                Line::from(Span::styled(
+                    line.text,
+                    Style::default().fg(COLOR_SECONDARY),
+                ))
+            } else {
+                Line::from(line.text)
+            }
+        })
+        .collect()
+}
+
+/// Tasks タブの行を構築する（タスク番号と問題の一覧を含む）
+fn task_lines(spec: &SpecSet) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let current_line = spec.current_task().map(|task| task.line);
+    let completion_issues = check_completion(&spec.task_tree);
+
+    for task in flatten_tasks(&spec.task_tree) {
+        let indent = "  ".repeat(task.depth);
+        let (checkbox, mut title_style) = match task.status {
+            TaskStatus::Done => ("[x]", Style::default().fg(COLOR_SUBTEXT)),
+            TaskStatus::InProgress => (
+                "[-]",
+                Style::default()
+                    .fg(COLOR_ACTIVE)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            TaskStatus::Todo => ("[ ]", Style::default().fg(COLOR_TEXT)),
+        };
+        // 実行中のタスクを強調表示
+        if current_line == Some(task.line) {
+            title_style = title_style.add_modifier(Modifier::REVERSED);
+        }
+
+        // 比較中は比較元から変化したタスクの行頭に印を付ける
+        let change = spec
+            .delta
+            .as_ref()
+            .and_then(|delta| delta.changes.get(&task.line).copied());
+        let mut spans = Vec::new();
+        if spec.delta.is_some() {
+            spans.push(match change {
+                Some(change) => Span::styled("▌", Style::default().fg(change_color(change))),
+                None => Span::raw(" "),
+            });
+        }
+        spans.extend([
+            Span::raw(indent.clone()),
+            Span::styled(checkbox, Style::default().fg(COLOR_SECONDARY)),
+            Span::raw(" "),
+        ]);
+        if let Some(id) = &task.id {
+            spans.push(Span::styled(
+                format!("{} ", id),
+                Style::default()
+                    .fg(COLOR_PRIMARY)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if task.optional {
+            spans.push(Span::styled(
+                task.title.clone(),
+                title_style.add_modifier(Modifier::ITALIC),
+            ));
+            spans.push(Span::styled(
+                " (optional)",
+                Style::default().fg(COLOR_SUBTEXT),
+            ));
+        } else {
+            spans.push(Span::styled(task.title.clone(), title_style));
+        }
+        // 親子の完了状態が食い違うタスク
+        if completion_issues
+            .iter()
+            .any(|issue| issue.line() == task.line)
+        {
+            spans.push(Span::styled(" ⚠", Style::default().fg(COLOR_WARNING)));
+        }
+        if let Some(change) = change {
+            spans.push(Span::styled(
+                format!("  ({})", change.label()),
+                Style::default()
+                    .fg(change_color(change))
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        lines.push(Line::from(spans));
+
+        for description in &task.description {
+            lines.push(Line::styled(
+                format!("{}    {}", indent, description),
+                Style::default().fg(COLOR_SUBTEXT),
+            ));
+        }
+    }
+
+    if lines.is_empty() {
+        lines.push(Line::styled(
+            "No tasks found",
+            Style::default().fg(COLOR_SUBTEXT),
+        ));
+    }
+
+    // 比較元にあり、作業ツリーで削除されたタスク
+    if let Some(delta) = spec
+        .delta
+        .as_ref()
+        .filter(|delta| !delta.removed.is_empty())
+    {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("Removed since the compared ref ({})", delta.removed.len()),
+            Style::default()
+                .fg(COLOR_WARNING)
+                .add_modifier(Modifier::BOLD),
+        ));
+        for title in &delta.removed {
+            lines.push(Line::styled(
+                format!("  {}", title),
+                Style::default()
+                    .fg(COLOR_SUBTEXT)
+                    .add_modifier(Modifier::CROSSED_OUT),
+            ));
+        }
+    }
+
+    let issues = check_task_ids(&spec.task_tree);
+    if !issues.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("⚠ Task ID issues ({})", issues.len()),
+            Style::default()
+                .fg(COLOR_WARNING)
+                .add_modifier(Modifier::BOLD),
+        ));
+        for issue in issues {
+            lines.push(Line::styled(
+                format!("  line {}: {}", issue.line(), issue),
+                Style::default().fg(COLOR_WARNING),
+            ));
+        }
+    }
+
+    if !completion_issues.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("⚠ Completion issues ({})", completion_issues.len()),
+            Style::default()
+                .fg(COLOR_WARNING)
+                .add_modifier(Modifier::BOLD),
+        ));
+        for issue in completion_issues {
+            lines.push(Line::styled(
+                format!("  line {}: {}", issue.line(), issue),
+                Style::default().fg(COLOR_WARNING),
+            ));
+        }
+    }
+
+    lines
+}
+
+/// Traceability タブの行を構築する
+///
+/// 要件ごとに完了タスクによるカバー率と受け入れ基準の参照タスクを表示し、
+/// 未実装の要件と存在しない要件への参照を末尾にまとめる。
+fn traceability_lines(spec: &SpecSet) -> Vec<Line<'static>> {
+    let traceability = build_traceability(&spec.requirement_list, &spec.task_tree);
+    let mut lines = Vec::new();
+
+    if traceability.requirements.is_empty() {
+        lines.push(Line::styled(
+            "No numbered requirements found",
+            Style::default().fg(COLOR_SUBTEXT),
+        ));
+    }
+
+    for requirement in &traceability.requirements {
+        let heading = if requirement.title.is_empty() {
+            format!("Requirement {}", requirement.id)
+        } else {
+            format!("Requirement {}: {}", requirement.id, requirement.title)
+        };
+        let coverage_style = if requirement.is_unimplemented() {
+            Style::default().fg(COLOR_WARNING)
+        } else {
+            Style::default().fg(COLOR_SECONDARY)
+        };
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                heading,
+                Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {:>3}% ({}/{} tasks done)",
+                    (requirement.coverage() * 100.0) as u16,
+                    requirement.completed_tasks(),
+                    requirement.tasks.len()
+                ),
+                coverage_style,
+            ),
+        ]));
+
+        for criterion in &requirement.criteria {
+            let tasks = if criterion.tasks.is_empty() {
+                Span::styled("no tasks", Style::default().fg(COLOR_WARNING))
+            } else {
+                let labels: Vec<String> = criterion
+                    .tasks
+                    .iter()
+                    .map(|task| {
+                        let mark = if task.completed { "x" } else { " " };
+                        format!("[{}] {}", mark, task.label)
+                    })
+                    .collect();
+                Span::styled(labels.join(", "), Style::default().fg(COLOR_SUBTEXT))
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<6}", criterion.id),
+                    Style::default().fg(COLOR_PRIMARY),
+                ),
+                tasks,
+            ]));
+        }
+    }
+
+    let unimplemented = traceability.unimplemented();
+    if !unimplemented.is_empty() {
+        let ids: Vec<&str> = unimplemented
+            .iter()
+            .map(|requirement| requirement.id.as_str())
+            .collect();
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!("⚠ Requirements without tasks: {}", ids.join(", ")),
+            Style::default()
+                .fg(COLOR_WARNING)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    if !traceability.dangling.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            format!(
+                "⚠ Unknown requirement references ({})",
+                traceability.dangling.len()
+            ),
+            Style::default()
+                .fg(COLOR_WARNING)
+                .add_modifier(Modifier::BOLD),
+        ));
+        for dangling in &traceability.dangling {
+            lines.push(Line::styled(
+                format!(
+                    "  line {}: task {} → {}",
+                    dangling.task.line, dangling.task.label, dangling.reference
+                ),
+                Style::default().fg(COLOR_WARNING),
+            ));
+        }
+    }
+
+    lines
+}
+
+/// リストビューをレンダリングする
+fn render_list_view(app: &mut App, frame: &mut Frame) {
+    let content_area = padded_area(frame.area());
+
+    // 絞り込み条件に一致する Spec
+    let visible: Vec<&SpecSet> = app
+        .visible_specs()
+        .into_iter()
+        .map(|index| &app.spec_sets[index])
+        .collect();
+
+    // Calculate overall progress（ゲージは任意タスクを除いた進捗を表示）
+    let mode = app.progress_mode;
+    let (progress_ratio, progress_text) = rollup(&visible, mode);
+
     // Main Layout
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // Header
             Constraint::Length(3), // Progress
+            Constraint::Length(1), // Phase summary
             Constraint::Min(0),    // List
             Constraint::Length(1), // Footer
         ])
         .split(content_area);
 
-    let version = env!("CARGO_PKG_VERSION");
-    let header = Paragraph::new(format!("[ KIRO RADAR - {} ]", version))
-        .alignment(Alignment::Right)
-        .style(
-            Style::default()
-                .fg(COLOR_SECONDARY)
-                .add_modifier(Modifier::BOLD),
-        );
-
-    frame.render_widget(header, chunks[0]);
+    render_header(app, chunks[0], frame);
 
     // Progress
     let label = Span::styled(
-        format!(
-            "{:.0}% ({}/{})",
-            progress_ratio * 100.0,
-            completed_tasks,
-            total_tasks
-        ),
+        progress_text,
         Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD),
     );
 
     let gauge = Gauge::default()
         .block(
             Block::default()
-                .title("Overall Progress")
+                .title(match mode {
+                    ProgressMode::All => "Overall Progress".to_string(),
+                    mode => format!("Overall Progress · {}", mode.label()),
+                })
                 .title_style(Style::default().fg(COLOR_SECONDARY))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
@@ -284,32 +1481,147 @@ fn render_list_view(app: &mut App, frame: &mut Frame) {
 
     frame.render_widget(gauge, chunks[1]);
 
-    // Spec List
-    let items: Vec<ListItem> = app
-        .spec_sets
+    // フェーズごとの Spec 数
+    let mut summary = Vec::new();
+    for phase in Phase::ALL {
+        let count = visible.iter().filter(|spec| spec.phase() == phase).count();
+        if !summary.is_empty() {
+            summary.push(Span::styled(" · ", Style::default().fg(COLOR_SUBTEXT)));
+        }
+        summary.push(Span::styled(
+            format!("● {} {}", phase.label(), count),
+            Style::default().fg(if count == 0 {
+                COLOR_SUBTEXT
+            } else {
+                phase_color(phase)
+            }),
+        ));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(summary)).block(Block::default().padding(Padding::horizontal(1))),
+        chunks[2],
+    );
+
+    // いずれかの Spec に値があるメタデータを列として表示
+    let columns: Vec<(&str, usize)> = METADATA_KEYS
         .iter()
-        .flat_map(|spec| {
-            let t = spec.total_tasks.unwrap_or(0);
-            let c = spec.completed_tasks.unwrap_or(0);
-            let p = if t > 0 { c as f64 / t as f64 } else { 0.0 };
-            let percent = (p * 100.0) as u16;
+        .filter_map(|&key| {
+            visible
+                .iter()
+                .filter_map(|spec| spec.metadata.field(key))
+                .map(|value| value.chars().count().min(METADATA_COLUMN_WIDTH))
+                .max()
+                .map(|width| (key, width))
+        })
+        .collect();
+
+    // git の履歴がある Spec があれば最終変更の列を表示
+    let show_last_touched = visible.iter().any(|spec| spec.git.is_some());
+
+    // Spec List（モノレポモードではパッケージごとに見出しと集計を挟む）
+    let grouped = visible.iter().any(|spec| !spec.package.is_empty());
+    let mut spec_rows = Vec::with_capacity(visible.len());
+    let mut row = 0;
+    let items: Vec<ListItem> = visible
+        .iter()
+        .enumerate()
+        .flat_map(|(position, spec)| {
+            let mut items = Vec::new();
+            if grouped && (position == 0 || visible[position - 1].package != spec.package) {
+                let package: Vec<&SpecSet> = visible
+                    .iter()
+                    .copied()
+                    .filter(|other| other.package == spec.package)
+                    .collect();
+                items.push(ListItem::new(package_header(&package, mode)));
+                row += 1;
+            }
+            spec_rows.push(row);
+            row += 1;
+
+            let (total_effort, completed_effort) = spec.effort(mode);
+            let label = with_effort(
+                progress_label(
+                    spec.completed_required_tasks(mode),
+                    spec.required_tasks(mode),
+                    spec.completed_tasks(mode),
+                    spec.total_tasks(mode),
+                ),
+                total_effort,
+                completed_effort,
+            );
 
             let name_style = Style::default().fg(COLOR_TEXT).add_modifier(Modifier::BOLD);
             let info_style = Style::default().fg(COLOR_SUBTEXT);
 
-            let line = Line::from(vec![
+            let phase = spec.phase();
+            let mut spans = vec![
                 Span::styled(format!("{:<20}", spec.name), name_style),
-                Span::styled(format!("  {:>3}% ({}/{})", percent, c, t), info_style),
-            ]);
+                Span::styled(
+                    format!("  {:<PHASE_COLUMN_WIDTH$}", phase.label()),
+                    Style::default().fg(phase_color(phase)),
+                ),
+            ];
+            if show_last_touched {
+                let age = spec.git.as_ref().map_or("-".to_string(), |git| git.age());
+                spans.push(Span::styled(
+                    format!("  {:<LAST_TOUCHED_COLUMN_WIDTH$}", age),
+                    info_style,
+                ));
+            }
+            for (key, width) in &columns {
+                let value = spec.metadata.field(key).unwrap_or("-");
+                spans.push(Span::styled(
+                    format!("  {:<width$}", truncate_text(value, *width), width = width),
+                    Style::default().fg(COLOR_SECONDARY),
+                ));
+            }
+            spans.push(Span::styled(format!("  {}", label), info_style));
+            if let Some(delta) = spec.delta.as_ref().filter(|delta| !delta.is_empty()) {
+                spans.push(Span::styled(
+                    format!("  Δ {}", delta.summary()),
+                    Style::default().fg(COLOR_SECONDARY),
+                ));
+            }
+            if !spec.errors.is_empty() {
+                spans.push(Span::styled(
+                    match spec.errors.len() {
+                        1 => "  ⚠ read error".to_string(),
+                        count => format!("  ⚠ {} read errors", count),
+                    },
+                    Style::default()
+                        .fg(COLOR_WARNING)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            if !spec.diagnostics.is_empty() {
+                spans.push(Span::styled(
+                    format!("  ⚠ {} diagnostics", spec.diagnostics.len()),
+                    Style::default().fg(COLOR_WARNING),
+                ));
+            }
+            if let Some(task) = spec.current_task() {
+                spans.push(Span::styled(
+                    format!(
+                        "  ▶ {} in progress: {}",
+                        spec.in_progress_tasks(),
+                        task_label(task)
+                    ),
+                    Style::default().fg(COLOR_ACTIVE),
+                ));
+            }
+
+            let line = Line::from(spans);
 
-            vec![ListItem::new(line)]
+            items.push(ListItem::new(line));
+            items
         })
         .collect();
 
     let list = List::new(items)
         .block(
             Block::default()
-                .title("Specs")
+                .title(list_title(app, visible.len()))
                 .title_style(Style::default().fg(COLOR_PRIMARY))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
@@ -322,9 +1634,34 @@ fn render_list_view(app: &mut App, frame: &mut Frame) {
                 .add_modifier(Modifier::BOLD),
         );
 
-    frame.render_stateful_widget(list, chunks[2], &mut app.list_state);
+    // 選択位置は Spec の並びの位置なので、見出し行を含めた行位置に変換して描画する
+    let mut list_state = app.list_state.clone();
+    list_state.select(
+        app.list_state
+            .selected()
+            .and_then(|selected| spec_rows.get(selected).copied()),
+    );
+    frame.render_stateful_widget(list, chunks[3], &mut list_state);
+    *app.list_state.offset_mut() = list_state.offset();
 
-    let footer = Paragraph::new("[ ↑↓/jk -> navigate ] [ q  -> quit ]")
+    let footer_text = if app.filter_editing {
+        format!(
+            "Filter: {}▏ [ Enter -> apply ] [ Esc -> clear ]",
+            app.filter
+        )
+    } else {
+        // 比較は `--against` を指定したときだけ切り替えられる
+        let compare = if app.against.is_some() {
+            "[ c -> compare ] "
+        } else {
+            ""
+        };
+        format!(
+            "[ ↑↓/jk -> navigate ] [ s -> sort ] [ / -> filter ] [ p -> progress ] {}[ r -> reload ] [ Tab -> steering ] [ q  -> quit ]",
+            compare
+        )
+    };
+    let footer = Paragraph::new(footer_text)
         .alignment(Alignment::Right)
         .style(
             Style::default()
@@ -332,25 +1669,176 @@ fn render_list_view(app: &mut App, frame: &mut Frame) {
                 .add_modifier(Modifier::BOLD),
         );
 
-    frame.render_widget(footer, chunks[3]);
+    frame.render_widget(footer, chunks[4]);
+}
+
+/// Spec リストのタイトル（並び順と絞り込み条件を表示）
+fn list_title(app: &App, visible: usize) -> String {
+    let mut title = String::from("Specs");
+    if app.comparing {
+        title.push_str(&format!(" · vs {}", app.comparison_ref()));
+        if !app.removed_specs.is_empty() {
+            title.push_str(&format!(" · removed: {}", app.removed_specs.join(", ")));
+        }
+    }
+    if app.sort_key != SortKey::Name {
+        title.push_str(&format!(" · sort: {}", app.sort_key.label()));
+    }
+    if !app.filter.is_empty() {
+        title.push_str(&format!(
+            " · filter: {} ({}/{})",
+            app.filter,
+            visible,
+            app.spec_sets.len()
+        ));
+    }
+    title
+}
+
+/// 文字数が `max` を超える場合は末尾を `…` に置き換える
+fn truncate_text(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        text.to_string()
+    } else {
+        let mut truncated: String = text.chars().take(max.saturating_sub(1)).collect();
+        truncated.push('…');
+        truncated
+    }
+}
+
+/// タスクの表示名（番号があれば番号付き）
+fn task_label(task: &Task) -> String {
+    match &task.id {
+        Some(id) => format!("{} {}", id, task.title),
+        None => task.title.clone(),
+    }
+}
+
+/// 完了数と総数から進捗率（0.0〜1.0）を計算する
+fn ratio(completed: usize, total: usize) -> f64 {
+    if total > 0 {
+        completed as f64 / total as f64
+    } else {
+        0.0
+    }
+}
+
+/// 進捗ラベルを生成する
+///
+/// 任意タスクがある場合は、任意タスクを含めた進捗も併記する。
+fn progress_label(
+    completed_required: usize,
+    required: usize,
+    completed_all: usize,
+    all: usize,
+) -> String {
+    let label = format!(
+        "{:>3}% ({}/{})",
+        (ratio(completed_required, required) * 100.0) as u16,
+        completed_required,
+        required
+    );
+
+    if all == required {
+        label
+    } else {
+        format!(
+            "{} · incl. optional {}% ({}/{})",
+            label,
+            (ratio(completed_all, all) * 100.0) as u16,
+            completed_all,
+            all
+        )
+    }
+}
+
+/// Spec 群の進捗を集計して (ゲージの比率, 進捗ラベル) を返す
+///
+/// 比率は任意タスクを除いた件数ベースの進捗。
+fn rollup(specs: &[&SpecSet], mode: ProgressMode) -> (f64, String) {
+    let total_tasks: usize = specs.iter().map(|s| s.total_tasks(mode)).sum();
+    let completed_tasks: usize = specs.iter().map(|s| s.completed_tasks(mode)).sum();
+    let required_tasks: usize = specs.iter().map(|s| s.required_tasks(mode)).sum();
+    let completed_required: usize = specs.iter().map(|s| s.completed_required_tasks(mode)).sum();
+    let (total_effort, completed_effort) = specs
+        .iter()
+        .map(|s| s.effort(mode))
+        .fold((0.0, 0.0), |(total, completed), (t, c)| {
+            (total + t, completed + c)
+        });
+
+    let label = with_effort(
+        progress_label(
+            completed_required,
+            required_tasks,
+            completed_tasks,
+            total_tasks,
+        ),
+        total_effort,
+        completed_effort,
+    );
+    (ratio(completed_required, required_tasks), label)
+}
+
+/// モノレポモードのパッケージ見出し行（パッケージ名と配下の Spec の集計）
+fn package_header(specs: &[&SpecSet], mode: ProgressMode) -> Line<'static> {
+    let package = specs.first().map_or("", |spec| spec.package.as_str());
+    let (_, label) = rollup(specs, mode);
+    Line::from(vec![
+        Span::styled(
+            format!("▾ {}", package),
+            Style::default()
+                .fg(COLOR_PRIMARY)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  {} spec{}  {}",
+                specs.len(),
+                if specs.len() == 1 { "" } else { "s" },
+                label
+            ),
+            Style::default().fg(COLOR_SUBTEXT),
+        ),
+    ])
+}
+
+/// フェーズの表示色
+fn phase_color(phase: Phase) -> Color {
+    match phase {
+        Phase::Requirements => COLOR_SUBTEXT,
+        Phase::Design => COLOR_SECONDARY,
+        Phase::Planned => COLOR_WARNING,
+        Phase::InProgress => COLOR_ACTIVE,
+        Phase::Done => COLOR_PRIMARY,
+    }
+}
+
+/// 見積もりのある場合に工数ベースの進捗を併記する
+fn with_effort(label: String, total: f64, completed: f64) -> String {
+    if total <= 0.0 {
+        return label;
+    }
+    format!(
+        "{} · effort {}% ({}/{})",
+        label,
+        (completed / total * 100.0) as u16,
+        format_hours(completed),
+        format_hours(total)
+    )
+}
+
+/// 時間を `8h` / `1.5h` の形式で表示する
+fn format_hours(hours: f64) -> String {
+    format!("{}h", (hours * 10.0).round() / 10.0)
 }
 
 /// 詳細ビューの最大スクロール位置を計算する
+///
+/// 描画時にキャッシュした行を使うため、キー入力のたびにファイルを読み直さない。
 pub fn calculate_max_scroll(app: &App, content_height: usize) -> usize {
-    if let ViewMode::Detail { spec_index } = &app.view_mode {
-        if let Some(spec) = app.spec_sets.get(*spec_index) {
-            let spec_content = read_spec_content(spec);
-            let content_text = match app.active_tab {
-                DetailTab::Requirements => spec_content.requirements,
-                DetailTab::Design => spec_content.design,
-                DetailTab::Tasks => spec_content.tasks,
-            };
-
-            if let Some(text) = content_text {
-                let total_lines = text.lines().count();
-                return total_lines.saturating_sub(content_height);
-            }
-        }
+    if app.current_spec().is_none() && app.current_steering_doc().is_none() {
+        return 0;
     }
-    0
+    current_lines(app).len().saturating_sub(content_height)
 }